@@ -0,0 +1,83 @@
+//! Provides [`rkyv`] integration.
+
+use ::rkyv::{
+    bytecheck::CheckBytes,
+    rancor::{Fallible, Infallible, Strategy},
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+use crate::{OneBased, UnsignedNonZero};
+
+/// Archived representation of [`OneBased`], created by [`rkyv`].
+///
+/// Its [`CheckBytes`] implementation validates that the archived value is not zero,
+/// so that the archive can be accessed without copying once validated.
+///
+/// ```
+/// # use one_based::OneBasedU32;
+/// use rkyv::{rancor::Error, Archived};
+///
+/// let v = OneBasedU32::from_one_based(3).unwrap();
+/// let bytes = rkyv::to_bytes::<Error>(&v).unwrap();
+/// let archived = rkyv::access::<Archived<OneBasedU32>, Error>(&bytes).unwrap();
+/// assert_eq!(archived.to_native(), v);
+///
+/// let zero = rkyv::to_bytes::<Error>(&0u32).unwrap();
+/// assert!(rkyv::access::<Archived<OneBasedU32>, Error>(&zero).is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[repr(transparent)]
+pub struct ArchivedOneBased<A>(A);
+
+// SAFETY: ArchivedOneBased<A> is repr(transparent) over A.
+unsafe impl<A: Portable> Portable for ArchivedOneBased<A> {}
+
+// SAFETY: ArchivedOneBased<A> is repr(transparent) over A,
+// and archived NonZero types check that the value is not zero.
+unsafe impl<C: Fallible + ?Sized, A: CheckBytes<C>> CheckBytes<C> for ArchivedOneBased<A> {
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        A::check_bytes(value.cast(), context)
+    }
+}
+
+impl<A> ArchivedOneBased<A> {
+    /// Returns the native `OneBased*` value.
+    pub fn to_native<T>(&self) -> OneBased<T>
+    where
+        T: UnsignedNonZero + Archive<Archived = A>,
+        A: Deserialize<T, Strategy<(), Infallible>>,
+    {
+        match ::rkyv::api::deserialize_using::<T, (), Infallible>(&self.0, &mut ()) {
+            Ok(v) => OneBased(v),
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl<T: UnsignedNonZero + Archive> Archive for OneBased<T> {
+    type Archived = ArchivedOneBased<T::Archived>;
+    type Resolver = T::Resolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: ArchivedOneBased<T::Archived> is repr(transparent) over T::Archived.
+        let out = unsafe { out.cast_unchecked::<T::Archived>() };
+        self.0.resolve(resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized, T: UnsignedNonZero + Serialize<S>> Serialize<S> for OneBased<T> {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<D, T> Deserialize<OneBased<T>, D> for ArchivedOneBased<T::Archived>
+where
+    D: Fallible + ?Sized,
+    T: UnsignedNonZero + Archive,
+    T::Archived: Deserialize<T, D>,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<OneBased<T>, D::Error> {
+        self.0.deserialize(deserializer).map(OneBased)
+    }
+}
@@ -0,0 +1,267 @@
+//! Provides atomic variants of `OneBased*` types.
+
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::AtomicU32;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::Ordering;
+
+use crate::OneBasedError;
+#[cfg(target_has_atomic = "32")]
+use crate::OneBasedU32;
+#[cfg(target_has_atomic = "64")]
+use crate::OneBasedU64;
+
+/// Returns the ordering usable for the load part of the given read-modify-write ordering.
+fn load_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
+
+macro_rules! define_atomic {
+    ($(#[$cfg:meta] $name:ident, $onebased:ident, $atomic:ident, $itype:ty);+) => {$(
+        #[doc = concat!(r"Atomic version of [`", stringify!($onebased), r"`], which can never hold 0.")]
+        ///
+        /// ```
+        #[doc = concat!(r" # use one_based::{", stringify!($name), r", ", stringify!($onebased), r"};")]
+        /// use std::sync::atomic::Ordering;
+        ///
+        #[doc = concat!(r" let next = ", stringify!($name), r"::new(", stringify!($onebased), r"::FIRST);")]
+        /// let seq = next.checked_fetch_add(1, Ordering::Relaxed)?;
+        /// assert_eq!(seq.as_zero_based(), 0);
+        /// assert_eq!(next.load(Ordering::Relaxed).as_zero_based(), 1);
+        ///
+        #[doc = concat!(r" next.store(", stringify!($onebased), r"::MAX, Ordering::Relaxed);")]
+        /// assert!(next.checked_fetch_add(1, Ordering::Relaxed).is_err());
+        #[doc = concat!(r" assert_eq!(next.load(Ordering::Relaxed), ", stringify!($onebased), r"::MAX);")]
+        /// # Ok::<(), one_based::OneBasedError>(())
+        /// ```
+        #[$cfg]
+        #[repr(transparent)]
+        pub struct $name($atomic);
+
+        #[$cfg]
+        impl $name {
+            /// Creates a new atomic holding the given index.
+            #[inline]
+            pub const fn new(v: $onebased) -> Self {
+                Self(<$atomic>::new(v.as_one_based().get()))
+            }
+
+            /// Consumes the atomic and returns the contained index.
+            #[inline]
+            pub fn into_inner(self) -> $onebased {
+                Self::from_raw(self.0.into_inner())
+            }
+
+            /// Converts the raw value held in the atomic.
+            #[inline]
+            fn from_raw(v: $itype) -> $onebased {
+                // SAFETY: only the values of `$onebased`, which are non-zero, are ever stored.
+                unsafe { <$onebased>::from_one_based_unchecked(v) }
+            }
+
+            /// Loads the index.
+            #[inline]
+            pub fn load(&self, order: Ordering) -> $onebased {
+                Self::from_raw(self.0.load(order))
+            }
+
+            /// Stores the index.
+            #[inline]
+            pub fn store(&self, v: $onebased, order: Ordering) {
+                self.0.store(v.as_one_based().get(), order)
+            }
+
+            /// Stores the index, returning the previous one.
+            #[inline]
+            pub fn swap(&self, v: $onebased, order: Ordering) -> $onebased {
+                Self::from_raw(self.0.swap(v.as_one_based().get(), order))
+            }
+
+            /// Stores `new` if the current index is same as `current`.
+            /// Returns the previous index, which is wrapped in `Err` if not updated.
+            #[inline]
+            pub fn compare_exchange(
+                &self,
+                current: $onebased,
+                new: $onebased,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$onebased, $onebased> {
+                self.0
+                    .compare_exchange(
+                        current.as_one_based().get(),
+                        new.as_one_based().get(),
+                        success,
+                        failure,
+                    )
+                    .map(Self::from_raw)
+                    .map_err(Self::from_raw)
+            }
+
+            /// Updates the index with the given function, until it succeeds or the function returns `None`.
+            /// Returns the previous index, which is wrapped in `Err` if not updated.
+            #[inline]
+            pub fn fetch_update<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> Result<$onebased, $onebased>
+            where
+                F: FnMut($onebased) -> Option<$onebased>,
+            {
+                self.0
+                    .fetch_update(set_order, fetch_order, |v| {
+                        f(Self::from_raw(v)).map(|v| v.as_one_based().get())
+                    })
+                    .map(Self::from_raw)
+                    .map_err(Self::from_raw)
+            }
+
+            /// Adds to the index, returning the previous index.
+            /// Returns [`OneBasedError::OutOfRangeIndex`] without update if the result overflows,
+            /// so that the atomic never wraps around to 0.
+            #[inline]
+            pub fn checked_fetch_add(&self, v: $itype, order: Ordering) -> Result<$onebased, OneBasedError> {
+                self.0
+                    .fetch_update(order, load_ordering(order), |current| current.checked_add(v))
+                    .map(Self::from_raw)
+                    .map_err(|current| OneBasedError::OutOfRangeIndex {
+                        value: current as u128 + v as u128,
+                        bits: <$itype>::BITS,
+                    })
+            }
+        }
+
+        #[$cfg]
+        impl From<$onebased> for $name {
+            #[inline]
+            fn from(v: $onebased) -> Self {
+                Self::new(v)
+            }
+        }
+
+        #[$cfg]
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.load(Ordering::Relaxed).as_one_based())
+                    .finish()
+            }
+        }
+    )*};
+}
+
+define_atomic!(
+    #[cfg(target_has_atomic = "32")] AtomicOneBasedU32, OneBasedU32, AtomicU32, u32;
+    #[cfg(target_has_atomic = "64")] AtomicOneBasedU64, OneBasedU64, AtomicU64, u64
+);
+
+/// Behavior of [`OneBasedCounter`] after handing out the largest index.
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CounterOverflow {
+    /// Returns [`OneBasedError::OutOfRangeIndex`] once all the indices are handed out.
+    #[default]
+    Error,
+    /// Keeps returning the largest index.
+    Saturate,
+}
+
+/// Thread-safe generator of successive indices starting at 1,
+/// such as ticket or sequence numbers.
+///
+/// ```
+/// # use one_based::{CounterOverflow, OneBasedCounter, OneBasedU64};
+/// let counter = OneBasedCounter::new();
+/// assert_eq!(counter.next()?.get(), 1);
+/// assert_eq!(counter.next()?.get(), 2);
+/// assert_eq!(counter.peek()?.get(), 3);
+///
+/// let counter = OneBasedCounter::starting_at(OneBasedU64::MAX);
+/// assert_eq!(counter.next()?, OneBasedU64::MAX);
+/// assert!(counter.next().is_err());
+///
+/// let counter = OneBasedCounter::starting_at(OneBasedU64::MAX).with_overflow(CounterOverflow::Saturate);
+/// assert_eq!(counter.next()?, OneBasedU64::MAX);
+/// assert_eq!(counter.next()?, OneBasedU64::MAX);
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug)]
+pub struct OneBasedCounter {
+    /// The next index to hand out, or 0 if all the indices are handed out.
+    next: AtomicU64,
+    overflow: CounterOverflow,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl OneBasedCounter {
+    /// Creates a counter starting at 1, which returns error on overflow.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::starting_at(OneBasedU64::FIRST)
+    }
+
+    /// Creates a counter starting at the given index, which returns error on overflow.
+    #[inline]
+    pub const fn starting_at(first: OneBasedU64) -> Self {
+        Self {
+            next: AtomicU64::new(first.as_one_based().get()),
+            overflow: CounterOverflow::Error,
+        }
+    }
+
+    /// Sets the behavior after handing out the largest index.
+    #[inline]
+    pub const fn with_overflow(self, overflow: CounterOverflow) -> Self {
+        Self {
+            next: self.next,
+            overflow,
+        }
+    }
+
+    /// Hands out the next index.
+    ///
+    /// Each index is handed out only once, unless the counter saturates.
+    /// This function doesn't synchronize other memory accesses.
+    pub fn next(&self) -> Result<OneBasedU64, OneBasedError> {
+        let saturate = self.overflow == CounterOverflow::Saturate;
+        let prev = self
+            .next
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| match v {
+                0 => None,
+                u64::MAX if saturate => None,
+                u64::MAX => Some(0),
+                v => Some(v + 1),
+            });
+        let (Ok(v) | Err(v)) = prev;
+        OneBasedU64::from_one_based(v).map_err(|_| exhausted())
+    }
+
+    /// Returns the index which will be handed out next, without consuming it.
+    pub fn peek(&self) -> Result<OneBasedU64, OneBasedError> {
+        OneBasedU64::from_one_based(self.next.load(Ordering::Relaxed)).map_err(|_| exhausted())
+    }
+}
+
+/// Returns the error reported by the exhausted [`OneBasedCounter`].
+#[cfg(target_has_atomic = "64")]
+fn exhausted() -> OneBasedError {
+    OneBasedError::OutOfRangeIndex {
+        value: u64::MAX as u128 + 1,
+        bits: u64::BITS,
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Default for OneBasedCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,211 @@
+//! Provides dense bitset of `OneBasedUsize` indices.
+
+use crate::OneBasedUsize;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Dense set of [`OneBasedUsize`] indices, such as selected columns or rows.
+///
+/// Index `i` is stored as the bit `i - 1`, so that no bit is wasted for 0.
+/// The memory grows with the largest index inserted.
+///
+/// ```
+/// # use one_based::{OneBasedBitSet, OneBasedUsize};
+/// let column = |i| OneBasedUsize::from_one_based(i).unwrap();
+/// let mut selected = OneBasedBitSet::new();
+/// selected.insert(column(1));
+/// selected.insert(column(3));
+/// assert!(selected.contains(column(3)));
+/// assert!(!selected.contains(column(2)));
+///
+/// let mut other = OneBasedBitSet::new();
+/// other.insert(column(2));
+/// other.insert(column(3));
+/// selected.intersect_with(&other);
+/// assert_eq!(selected.iter().map(|i| i.get()).collect::<Vec<_>>(), [3]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OneBasedBitSet {
+    words: Vec<u64>,
+}
+
+/// Returns the position of the word and the mask of the bit for the index.
+#[inline]
+fn locate(index: OneBasedUsize) -> (usize, u64) {
+    let bit = index.as_zero_based();
+    (bit / WORD_BITS, 1 << (bit % WORD_BITS))
+}
+
+impl OneBasedBitSet {
+    /// Creates an empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { words: Vec::new() }
+    }
+
+    /// Creates an empty set, which can hold the indices up to `max` without reallocation.
+    #[inline]
+    pub fn with_max(max: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(max.div_ceil(WORD_BITS)),
+        }
+    }
+
+    /// Returns `true` if the set contains no index.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Returns the number of the indices in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if the set contains the given index.
+    #[inline]
+    pub fn contains(&self, index: OneBasedUsize) -> bool {
+        let (word, mask) = locate(index);
+        self.words.get(word).is_some_and(|w| w & mask != 0)
+    }
+
+    /// Adds the index to the set.
+    /// Returns `true` if the index was not in the set.
+    pub fn insert(&mut self, index: OneBasedUsize) -> bool {
+        let (word, mask) = locate(index);
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let inserted = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        inserted
+    }
+
+    /// Removes the index from the set.
+    /// Returns `true` if the index was in the set.
+    pub fn remove(&mut self, index: OneBasedUsize) -> bool {
+        let (word, mask) = locate(index);
+        match self.words.get_mut(word) {
+            Some(w) if *w & mask != 0 => {
+                *w &= !mask;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Removes all the indices.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    /// Adds all the indices in `other` to `self`.
+    pub fn union_with(&mut self, other: &Self) {
+        if self.words.len() < other.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (w, o) in self.words.iter_mut().zip(&other.words) {
+            *w |= o;
+        }
+    }
+
+    /// Removes the indices not in `other` from `self`.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.words.truncate(other.words.len());
+        for (w, o) in self.words.iter_mut().zip(&other.words) {
+            *w &= o;
+        }
+    }
+
+    /// Removes the indices in `other` from `self`.
+    pub fn difference_with(&mut self, other: &Self) {
+        for (w, o) in self.words.iter_mut().zip(&other.words) {
+            *w &= !o;
+        }
+    }
+
+    /// Returns the iterator over the indices in ascending order.
+    #[inline]
+    pub fn iter(&self) -> BitSetIter<'_> {
+        BitSetIter {
+            words: self.words.iter().enumerate(),
+            base: 0,
+            current: 0,
+        }
+    }
+}
+
+impl PartialEq for OneBasedBitSet {
+    /// Compares the contained indices, regardless of the allocated words.
+    fn eq(&self, other: &Self) -> bool {
+        let (short, long) = if self.words.len() <= other.words.len() {
+            (&self.words, &other.words)
+        } else {
+            (&other.words, &self.words)
+        };
+        short == &long[..short.len()] && long[short.len()..].iter().all(|&w| w == 0)
+    }
+}
+
+impl Eq for OneBasedBitSet {}
+
+impl Extend<OneBasedUsize> for OneBasedBitSet {
+    fn extend<I: IntoIterator<Item = OneBasedUsize>>(&mut self, iter: I) {
+        for index in iter {
+            self.insert(index);
+        }
+    }
+}
+
+impl core::iter::FromIterator<OneBasedUsize> for OneBasedBitSet {
+    fn from_iter<I: IntoIterator<Item = OneBasedUsize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a OneBasedBitSet {
+    type Item = OneBasedUsize;
+    type IntoIter = BitSetIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the indices of [`OneBasedBitSet`].
+///
+/// This is created by [`OneBasedBitSet::iter`].
+#[derive(Debug, Clone)]
+pub struct BitSetIter<'a> {
+    words: core::iter::Enumerate<core::slice::Iter<'a, u64>>,
+    /// 0-based index of the lowest bit in `current`.
+    base: usize,
+    /// Remaining bits of the current word.
+    current: u64,
+}
+
+impl Iterator for BitSetIter<'_> {
+    type Item = OneBasedUsize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            let (i, &word) = self.words.next()?;
+            self.base = i * WORD_BITS;
+            self.current = word;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // clears the lowest set bit.
+        self.current &= self.current - 1;
+        Some(
+            OneBasedUsize::from_zero_based_opt(self.base + bit)
+                .expect("inserted index must be valid"),
+        )
+    }
+}
+
+impl core::iter::FusedIterator for BitSetIter<'_> {}
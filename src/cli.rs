@@ -0,0 +1,140 @@
+//! Provides [`clap`] integration.
+
+use core::ops::{Bound, RangeBounds};
+use std::ffi::OsStr;
+
+use ::clap::{
+    builder::{TypedValueParser, ValueParserFactory},
+    error::ErrorKind,
+    Arg, Command,
+};
+
+use crate::{
+    OneBased, OneBasedInteger, ParseOneBasedError, ParseOneBasedErrorKind, UnsignedNonZero,
+};
+
+/// Value parser of `OneBased*` types for [`clap`], with optional range bounds.
+///
+/// This is used by default for `OneBased*` arguments through [`ValueParserFactory`],
+/// which reports errors such as "indices start at 1" for the given argument.
+///
+/// ```
+/// # use one_based::{OneBasedU32, OneBasedValueParser};
+/// use clap::Parser;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[arg(long)]
+///     line: OneBasedU32,
+///     #[arg(long, value_parser = OneBasedValueParser::<OneBasedU32>::new().range(..=80))]
+///     column: Option<OneBasedU32>,
+/// }
+///
+/// let args = Args::try_parse_from(["prog", "--line", "3"]).unwrap();
+/// assert_eq!(args.line.as_zero_based(), 2);
+///
+/// let err = Args::try_parse_from(["prog", "--line", "0"]).err().unwrap();
+/// assert!(err.to_string().contains("invalid value '0' for '--line <LINE>': indices start at 1"));
+///
+/// let err = Args::try_parse_from(["prog", "--line", "1", "--column", "81"]).err().unwrap();
+/// assert!(err.to_string().contains("index must be <= 80"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OneBasedValueParser<T: OneBasedInteger> {
+    start: Bound<T::Int>,
+    end: Bound<T::Int>,
+}
+
+impl<T: OneBasedInteger> OneBasedValueParser<T> {
+    /// Creates a parser accepting any valid 1-based index.
+    pub fn new() -> Self {
+        Self {
+            start: Bound::Unbounded,
+            end: Bound::Unbounded,
+        }
+    }
+
+    /// Restricts the accepted 1-based values to the given range, such as `1..=100`.
+    pub fn range<R: RangeBounds<T::Int>>(self, range: R) -> Self {
+        Self {
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+
+    /// Returns the reason why the value is out of range, if any.
+    fn check_range(&self, v: T::Int) -> Option<std::string::String> {
+        if (self.start, self.end).contains(&v) {
+            return None;
+        }
+        let start = match self.start {
+            Bound::Included(s) => Some(format!(">= {}", s)),
+            Bound::Excluded(s) => Some(format!("> {}", s)),
+            Bound::Unbounded => None,
+        };
+        let end = match self.end {
+            Bound::Included(e) => Some(format!("<= {}", e)),
+            Bound::Excluded(e) => Some(format!("< {}", e)),
+            Bound::Unbounded => None,
+        };
+        let bounds: Vec<_> = start.into_iter().chain(end).collect();
+        Some(format!("index must be {}", bounds.join(" and ")))
+    }
+}
+
+impl<T: OneBasedInteger> Default for OneBasedValueParser<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TypedValueParser for OneBasedValueParser<T>
+where
+    T: OneBasedInteger + Send + Sync + 'static,
+    T::Int: Send + Sync + 'static,
+{
+    type Value = T;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, ::clap::Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| ::clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        let reason = match value.parse::<T>() {
+            Ok(v) => match self.check_range(v.as_one_based().get()) {
+                None => return Ok(v),
+                Some(reason) => reason,
+            },
+            Err(e) => match ParseOneBasedError::new(value, &e).kind() {
+                ParseOneBasedErrorKind::Empty => "no number given",
+                ParseOneBasedErrorKind::InvalidNumber => "not a number",
+                ParseOneBasedErrorKind::OutOfRange => "number too large",
+                ParseOneBasedErrorKind::Zero => "indices start at 1",
+            }
+            .to_owned(),
+        };
+        let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+        Err(::clap::Error::raw(
+            ErrorKind::ValueValidation,
+            format!("invalid value '{}' for '{}': {}\n", value, arg, reason),
+        )
+        .with_cmd(cmd))
+    }
+}
+
+impl<T> ValueParserFactory for OneBased<T>
+where
+    T: UnsignedNonZero,
+    Self: OneBasedInteger + Send + Sync + 'static,
+    <Self as OneBasedInteger>::Int: Send + Sync + 'static,
+{
+    type Parser = OneBasedValueParser<Self>;
+
+    fn value_parser() -> Self::Parser {
+        OneBasedValueParser::new()
+    }
+}
@@ -0,0 +1,127 @@
+//! Provides parser of English number words such as "twenty-first".
+
+use core::fmt::Display;
+
+/// Error type used when parsing English number words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWordsError;
+
+impl Display for ParseWordsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid English number words, expected \"one\" to \"one hundred\"")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseWordsError {}
+
+const SMALL_CARDINALS: [&str; 20] = [
+    "",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const SMALL_ORDINALS: [&str; 20] = [
+    "",
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+];
+
+const TENS_CARDINALS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const TENS_ORDINALS: [&str; 10] = [
+    "",
+    "",
+    "twentieth",
+    "thirtieth",
+    "fortieth",
+    "fiftieth",
+    "sixtieth",
+    "seventieth",
+    "eightieth",
+    "ninetieth",
+];
+
+fn position(table: &[&str], word: &str) -> Option<u8> {
+    table
+        .iter()
+        .position(|w| !w.is_empty() && w.eq_ignore_ascii_case(word))
+        .map(|i| i as u8)
+}
+
+fn is_hundred(word: &str) -> bool {
+    word.eq_ignore_ascii_case("hundred") || word.eq_ignore_ascii_case("hundredth")
+}
+
+/// Parses English cardinal or ordinal number words between 1 and 100.
+pub(crate) fn parse_words(s: &str) -> Result<u8, ParseWordsError> {
+    let mut words = s
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|w| !w.is_empty());
+    let first = words.next().ok_or(ParseWordsError)?;
+    let value = if is_hundred(first) {
+        100
+    } else if first.eq_ignore_ascii_case("a") || first.eq_ignore_ascii_case("one") {
+        match words.next() {
+            None if first.eq_ignore_ascii_case("one") => 1,
+            Some(w) if is_hundred(w) => 100,
+            _ => return Err(ParseWordsError),
+        }
+    } else if let Some(v) = position(&SMALL_CARDINALS, first) {
+        v
+    } else if let Some(v) = position(&SMALL_ORDINALS, first) {
+        v
+    } else if let Some(v) = position(&TENS_ORDINALS, first) {
+        v * 10
+    } else if let Some(v) = position(&TENS_CARDINALS, first) {
+        let unit = match words.next() {
+            None => 0,
+            Some(w) => position(&SMALL_CARDINALS[..10], w)
+                .or_else(|| position(&SMALL_ORDINALS[..10], w))
+                .ok_or(ParseWordsError)?,
+        };
+        v * 10 + unit
+    } else {
+        return Err(ParseWordsError);
+    };
+    if words.next().is_some() {
+        return Err(ParseWordsError);
+    }
+    Ok(value)
+}
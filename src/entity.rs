@@ -0,0 +1,75 @@
+//! Provides [`sea_orm`] integration.
+//!
+//! Same as `sqlx` integration, `OneBased*` types are stored as signed integer
+//! which can hold all the 1-based values,
+//! as unsigned integers are not supported on Postgres:
+//! `OneBasedU8` as `SmallInteger`, `OneBasedU16` as `Integer`,
+//! and `OneBasedU32` and `OneBasedU64` as `BigInteger`.
+
+use ::sea_orm::{
+    sea_query::{ArrayType, ColumnType, Nullable, ValueType, ValueTypeErr},
+    ColIdx, DbErr, QueryResult, TryGetError, TryGetable, Value,
+};
+
+use crate::{OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8};
+
+macro_rules! impl_sea_orm {
+    (@common $name:ident, $stype:ty) => {
+        impl TryGetable for $name {
+            fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+                let v = <$stype as TryGetable>::try_get_by(res, index)?;
+                <$name>::try_from_db_i64(v.into())
+                    .map_err(|e| TryGetError::DbErr(DbErr::Type(e.to_string())))
+            }
+        }
+
+        impl ValueType for $name {
+            fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+                let v = <$stype as ValueType>::try_from(v)?;
+                <$name>::try_from_db_i64(v.into()).map_err(|_| ValueTypeErr)
+            }
+
+            fn type_name() -> String {
+                stringify!($name).to_owned()
+            }
+
+            fn array_type() -> ArrayType {
+                <$stype as ValueType>::array_type()
+            }
+
+            fn column_type() -> ColumnType {
+                <$stype as ValueType>::column_type()
+            }
+        }
+
+        impl Nullable for $name {
+            fn null() -> Value {
+                <$stype as Nullable>::null()
+            }
+        }
+    };
+    (lossless: $($name:ident, $stype:ty);+) => {$(
+        impl_sea_orm!(@common $name, $stype);
+
+        impl From<$name> for Value {
+            #[doc = concat!(r"Converts [`", stringify!($name), r"`] to 1-based `", stringify!($stype), r"` value.")]
+            fn from(value: $name) -> Self {
+                <$stype>::from(value.as_one_based().get()).into()
+            }
+        }
+    )*};
+}
+
+impl_sea_orm!(lossless: OneBasedU8, i16; OneBasedU16, i32; OneBasedU32, i64);
+impl_sea_orm!(@common OneBasedU64, i64);
+
+impl From<OneBasedU64> for Value {
+    /// Converts [`OneBasedU64`] to 1-based `i64` value,
+    /// or `u64` value if it doesn't fit in `i64`.
+    fn from(value: OneBasedU64) -> Self {
+        match value.try_as_db_i64() {
+            Ok(v) => v.into(),
+            Err(_) => value.as_one_based().get().into(),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Provides [`fake`] integration.
+//!
+//! `OneBased*` types implement [`Dummy<Faker>`] generating any valid index `1..=MAX`,
+//! and also take the range of 1-based values as faker,
+//! so that the realistic positions or ranks can be generated.
+//!
+//! ```
+//! # use one_based::OneBasedU32;
+//! use fake::{Dummy, Fake, Faker};
+//!
+//! #[derive(Dummy)]
+//! struct Entry {
+//!     #[dummy(faker = "1..=10")]
+//!     rank: OneBasedU32,
+//!     #[dummy(faker = "..=100")]
+//!     page: OneBasedU32,
+//! }
+//!
+//! let entry: Entry = Faker.fake();
+//! assert!(entry.rank.as_zero_based() < 10);
+//! assert!(entry.page.as_zero_based() < 100);
+//! ```
+
+use core::ops::{Range, RangeInclusive, RangeTo, RangeToInclusive};
+
+use ::fake::{Dummy, Faker, Rng};
+
+use crate::{OneBasedU128, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize};
+
+const ZERO_RANGE_MESSAGE: &str = "1-based range must start from 1";
+
+macro_rules! impl_fake {
+    ($($name:ident, $itype:ty);+) => {$(
+        impl Dummy<Faker> for $name {
+            fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+                <$name>::from_one_based_opt(rng.random_range(1..=<$itype>::MAX)).expect("must be non-zero")
+            }
+        }
+
+        impl Dummy<Range<$itype>> for $name {
+            /// Generates from the given 1-based range.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range starts from 0, or the range is empty.
+            fn dummy_with_rng<R: Rng + ?Sized>(range: &Range<$itype>, rng: &mut R) -> Self {
+                assert_ne!(range.start, 0, "{}", ZERO_RANGE_MESSAGE);
+                <$name>::from_one_based_opt(rng.random_range(range.clone())).expect("must be non-zero")
+            }
+        }
+
+        impl Dummy<RangeInclusive<$itype>> for $name {
+            /// Generates from the given 1-based range.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range starts from 0, or the range is empty.
+            fn dummy_with_rng<R: Rng + ?Sized>(range: &RangeInclusive<$itype>, rng: &mut R) -> Self {
+                assert_ne!(*range.start(), 0, "{}", ZERO_RANGE_MESSAGE);
+                <$name>::from_one_based_opt(rng.random_range(range.clone())).expect("must be non-zero")
+            }
+        }
+
+        impl Dummy<RangeTo<$itype>> for $name {
+            /// Generates from the 1-based range starting from 1.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty.
+            fn dummy_with_rng<R: Rng + ?Sized>(range: &RangeTo<$itype>, rng: &mut R) -> Self {
+                Self::dummy_with_rng(&(1..range.end), rng)
+            }
+        }
+
+        impl Dummy<RangeToInclusive<$itype>> for $name {
+            /// Generates from the 1-based range starting from 1.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty.
+            fn dummy_with_rng<R: Rng + ?Sized>(range: &RangeToInclusive<$itype>, rng: &mut R) -> Self {
+                Self::dummy_with_rng(&(1..=range.end), rng)
+            }
+        }
+    )*};
+}
+
+impl_fake!(
+    OneBasedU8, u8;
+    OneBasedU16, u16;
+    OneBasedU32, u32;
+    OneBasedU64, u64;
+    OneBasedU128, u128;
+    OneBasedUsize, usize
+);
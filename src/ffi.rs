@@ -0,0 +1,61 @@
+//! Helpers to pass `OneBased*` values across C FFI boundary.
+//!
+//! `OneBased*` types are `#[repr(transparent)]` over `NonZero*` types,
+//! so they have the same size and alignment as the underlying primitive integer.
+//! In addition, `Option<OneBased*>` is guaranteed to have the same layout,
+//! with `None` represented as 0.
+//!
+//! However, tools like `cbindgen` don't understand the generic [`OneBased`](crate::OneBased) type,
+//! so use the aliases such as [`OneBasedU32Ffi`] in the exported signature
+//! to render them as plain integer, and validate the value at the boundary
+//! with `from_ffi` methods.
+//!
+//! ```
+//! use one_based::{ffi::OneBasedU32Ffi, OneBasedU32};
+//!
+//! /// Returns the 0-based offset of the given 1-based line, or -1 if the line is invalid.
+//! #[no_mangle]
+//! pub extern "C" fn line_offset(line: OneBasedU32Ffi) -> i64 {
+//!     match OneBasedU32::from_ffi(line) {
+//!         Some(line) => line.as_zero_based().into(),
+//!         None => -1,
+//!     }
+//! }
+//!
+//! assert_eq!(line_offset(3), 2);
+//! assert_eq!(line_offset(0), -1);
+//! ```
+
+use crate::{OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize};
+
+macro_rules! define_ffi {
+    ($($name:ident, $ffiname:ident, $itype:ty);+) => {$(
+        #[doc = concat!(r"FFI representation of [`", stringify!($name), r"`], holding the 1-based value.")]
+        ///
+        /// 0 is not a valid index, which may be used to represent `None`.
+        pub type $ffiname = $itype;
+
+        impl $name {
+            /// Creates from the 1-based value passed through FFI.
+            /// Returns `None` if the given value is 0.
+            #[inline]
+            pub const fn from_ffi(v: $ffiname) -> Option<Self> {
+                Self::from_one_based_opt(v)
+            }
+
+            /// Returns the 1-based value to pass through FFI, which is never 0.
+            #[inline]
+            pub const fn to_ffi(self) -> $ffiname {
+                self.as_one_based().get()
+            }
+        }
+    )*};
+}
+
+define_ffi!(
+    OneBasedU8, OneBasedU8Ffi, u8;
+    OneBasedU16, OneBasedU16Ffi, u16;
+    OneBasedU32, OneBasedU32Ffi, u32;
+    OneBasedU64, OneBasedU64Ffi, u64;
+    OneBasedUsize, OneBasedUsizeFfi, usize
+);
@@ -0,0 +1,203 @@
+//! Provides parser of `cut` style field list such as "1,3-5,7-".
+
+use core::{fmt::Display, num::ParseIntError, str::FromStr};
+
+use crate::{parse, OneBasedRange, OneBasedUsize};
+
+/// Error type used when parsing [`FieldList`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseFieldListError {
+    /// The list or one of the fields is empty.
+    EmptyField,
+    /// The index is invalid.
+    InvalidIndex(ParseIntError),
+    /// The end index of the range is smaller than the start index.
+    Reversed,
+}
+
+impl Display for ParseFieldListError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseFieldListError::EmptyField => f.write_str("empty field in the field list"),
+            ParseFieldListError::InvalidIndex(e) => write!(f, "invalid field index: {}", e),
+            ParseFieldListError::Reversed => {
+                f.write_str("field range end is smaller than the start")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseFieldListError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseFieldListError::InvalidIndex(e) => Some(e),
+            ParseFieldListError::EmptyField | ParseFieldListError::Reversed => None,
+        }
+    }
+}
+
+/// Range of the fields, where `end` is `None` for the open-ended tail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Field {
+    start: OneBasedUsize,
+    end: Option<OneBasedUsize>,
+}
+
+impl Field {
+    fn contains(&self, index: OneBasedUsize) -> bool {
+        self.start <= index && self.end.map_or(true, |end| index <= end)
+    }
+}
+
+/// List of 1-based fields given in `cut` syntax, such as "1,3-5,7-".
+///
+/// Each field is a single index "N", a closed range "N-M",
+/// an open-ended tail "N-" or a head "-M", separated by `,`.
+/// The fields are sorted and merged, so that they are iterated in ascending order without duplicates.
+///
+/// ```
+/// # use one_based::{FieldList, OneBasedUsize};
+/// let fields: FieldList = "7-,1,3-5".parse()?;
+/// assert_eq!(fields.to_string(), "1,3-5,7-");
+/// assert!(fields.contains(OneBasedUsize::from_one_based(4)?));
+/// assert!(!fields.contains(OneBasedUsize::from_one_based(6)?));
+///
+/// let columns: Vec<usize> = fields.iter(8).map(|i| i.get()).collect();
+/// assert_eq!(columns, [1, 3, 4, 5, 7, 8]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldList {
+    /// Disjoint fields sorted by the start index.
+    fields: Vec<Field>,
+}
+
+impl FieldList {
+    /// Returns `true` if the given index is selected.
+    pub fn contains(&self, index: OneBasedUsize) -> bool {
+        // the last field starting at or before `index` is the only candidate.
+        let i = self.fields.partition_point(|f| f.start <= index);
+        i > 0 && self.fields[i - 1].contains(index)
+    }
+
+    /// Returns the iterator over the selected indices within a record of `len` fields.
+    pub fn iter(&self, len: usize) -> FieldIndices<'_> {
+        FieldIndices {
+            fields: self.fields.iter(),
+            current: None,
+            last: OneBasedUsize::last_of(len),
+        }
+    }
+}
+
+/// Parses the field index, which only consists of ASCII digits.
+fn parse_index(s: &str) -> Result<OneBasedUsize, ParseFieldListError> {
+    if !s.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(ParseFieldListError::InvalidIndex(
+            parse::invalid_digit_error(),
+        ));
+    }
+    s.parse().map_err(ParseFieldListError::InvalidIndex)
+}
+
+/// Parses a single field, which is either an index or a range.
+fn parse_field(s: &str) -> Result<Field, ParseFieldListError> {
+    let (start, end) = match s.split_once('-') {
+        _ if s.is_empty() || s == "-" => return Err(ParseFieldListError::EmptyField),
+        None => {
+            let index = parse_index(s)?;
+            return Ok(Field {
+                start: index,
+                end: Some(index),
+            });
+        }
+        Some(range) => range,
+    };
+    let start = match start {
+        "" => OneBasedUsize::FIRST,
+        start => parse_index(start)?,
+    };
+    let end = match end {
+        "" => None,
+        end => Some(parse_index(end)?),
+    };
+    if end.is_some_and(|end| end < start) {
+        return Err(ParseFieldListError::Reversed);
+    }
+    Ok(Field { start, end })
+}
+
+impl FromStr for FieldList {
+    type Err = ParseFieldListError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s
+            .split(',')
+            .map(parse_field)
+            .collect::<Result<Vec<_>, _>>()?;
+        fields.sort_unstable_by_key(|f| f.start);
+        let mut merged: Vec<Field> = Vec::with_capacity(fields.len());
+        for field in fields {
+            match merged.last_mut() {
+                // merge the overlapping or adjacent fields.
+                Some(last)
+                    if last
+                        .end
+                        .map_or(true, |end| field.start.as_zero_based() <= end.get()) =>
+                {
+                    last.end = match (last.end, field.end) {
+                        (Some(x), Some(y)) => Some(x.max(y)),
+                        _ => None,
+                    };
+                }
+                _ => merged.push(field),
+            }
+        }
+        Ok(FieldList { fields: merged })
+    }
+}
+
+impl Display for FieldList {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            match field.end {
+                Some(end) if end == field.start => write!(f, "{}", end)?,
+                Some(end) => write!(f, "{}-{}", field.start, end)?,
+                None => write!(f, "{}-", field.start)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the indices selected by [`FieldList`].
+///
+/// This is created by [`FieldList::iter`].
+#[derive(Debug, Clone)]
+pub struct FieldIndices<'a> {
+    fields: core::slice::Iter<'a, Field>,
+    current: Option<OneBasedRange<OneBasedUsize>>,
+    /// The last index of the record, or `None` if the record is empty.
+    last: Option<OneBasedUsize>,
+}
+
+impl Iterator for FieldIndices<'_> {
+    type Item = OneBasedUsize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(index) = self.current.as_mut().and_then(Iterator::next) {
+                return Some(index);
+            }
+            let last = self.last?;
+            let field = self.fields.next()?;
+            let end = field.end.map_or(last, |end| end.min(last));
+            self.current = Some(field.start.iter_to(end));
+        }
+    }
+}
+
+impl core::iter::FusedIterator for FieldIndices<'_> {}
@@ -0,0 +1,254 @@
+//! Provides display adapters for `OneBased*` types.
+
+use core::fmt::Display;
+
+use crate::{OneBased, OneBasedInteger, UnsignedNonZero};
+
+/// Display adapter, which formats the 0-based value of `OneBased*` types.
+///
+/// Created by [`OneBased::display_zero_based()`].
+///
+/// ```
+/// # use one_based::OneBasedU32;
+/// let v = OneBasedU32::from_one_based(5)?;
+/// assert_eq!(format!("{} / {}", v, v.display_zero_based()), "5 / 4");
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayZeroBased<I>(I);
+
+impl<I: OneBasedInteger> Display for DisplayZeroBased<I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.as_zero_based().fmt(f)
+    }
+}
+
+impl<T: UnsignedNonZero> OneBased<T>
+where
+    Self: OneBasedInteger,
+{
+    /// Returns an adapter to display the 0-based value.
+    /// Note the regular [`Display`] formats the 1-based value.
+    #[inline]
+    pub fn display_zero_based(&self) -> DisplayZeroBased<Self> {
+        DisplayZeroBased(*self)
+    }
+}
+
+/// Debug adapter, which formats both 1-based and 0-based values of `OneBased*` types.
+///
+/// Created by `debug_both()` method of `OneBased*` types.
+///
+/// ```
+/// # use one_based::OneBasedU32;
+/// let v = OneBasedU32::from_one_based(5)?;
+/// assert_eq!(format!("{:?}", v.debug_both()), "OneBasedU32 { one: 5, zero: 4 }");
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+#[derive(Clone, Copy)]
+pub struct DebugBoth<I> {
+    name: &'static str,
+    value: I,
+}
+
+impl<I> DebugBoth<I> {
+    pub(crate) const fn new(name: &'static str, value: I) -> Self {
+        Self { name, value }
+    }
+}
+
+impl<I: OneBasedInteger> core::fmt::Debug for DebugBoth<I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct(self.name)
+            .field("one", &self.value.as_one_based())
+            .field("zero", &self.value.as_zero_based())
+            .finish()
+    }
+}
+
+/// Display adapter, which formats the 1-based value with English ordinal suffix,
+/// such as "1st", "2nd", "3rd", "4th" and "11th".
+///
+/// Created by `ordinal()` method of `OneBased*` types.
+///
+/// ```
+/// # use one_based::OneBasedU32;
+/// let v = OneBasedU32::from_one_based(21)?;
+/// assert_eq!(format!("You are the {} visitor", v.ordinal()), "You are the 21st visitor");
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Ordinal<I> {
+    value: I,
+    suffix: &'static str,
+}
+
+impl<I> Ordinal<I> {
+    /// `rem100` must be the 1-based value modulo 100.
+    pub(crate) const fn new(value: I, rem100: u8) -> Self {
+        let suffix = match (rem100 % 10, rem100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        };
+        Self { value, suffix }
+    }
+
+    pub(crate) const fn suffix(&self) -> &'static str {
+        self.suffix
+    }
+}
+
+impl<I: OneBasedInteger> Display for Ordinal<I> {
+    /// Formats the ordinal as a whole, respecting the width, fill and alignment.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write as _;
+
+        // the digits followed by 2 letters suffix.
+        let mut buf = [0u8; MAX_DIGITS + 2];
+        let mut w = SliceWriter::new(&mut buf);
+        write!(w, "{}{}", self.value, self.suffix)?;
+        f.pad(w.into_str())
+    }
+}
+
+/// Number of digits enough to hold any `u128` value.
+pub(crate) const MAX_DIGITS: usize = 39;
+
+/// Stack-backed buffer holding the formatted 1-based value,
+/// which is rendered without `core::fmt` machinery.
+///
+/// Created by `to_buffer()` method of `OneBased*` types.
+///
+/// ```
+/// # use one_based::OneBasedU32;
+/// let v = OneBasedU32::from_one_based(42)?;
+/// assert_eq!(v.to_buffer().as_str(), "42");
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+#[derive(Clone, Copy)]
+pub struct FormatBuffer {
+    buf: [u8; MAX_DIGITS],
+    start: usize,
+}
+
+impl FormatBuffer {
+    /// `buf[start..]` must consist of ASCII digits.
+    pub(crate) const fn new(buf: [u8; MAX_DIGITS], start: usize) -> Self {
+        Self { buf, start }
+    }
+
+    /// Returns the formatted value.
+    pub fn as_str(&self) -> &str {
+        // only ASCII digits are written into the buffer.
+        core::str::from_utf8(&self.buf[self.start..]).expect("buffer must be valid UTF-8")
+    }
+}
+
+impl core::ops::Deref for FormatBuffer {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for FormatBuffer {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl core::fmt::Debug for FormatBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for FormatBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// [`core::fmt::Write`] implementation which writes into the fixed buffer.
+pub(crate) struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    pub(crate) fn into_str(self) -> &'a str {
+        // only str is written into the buffer.
+        core::str::from_utf8(&self.buf[..self.len]).expect("buffer must be valid UTF-8")
+    }
+}
+
+impl core::fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Display adapter, which formats the 1-based value with [`num_format::Format`],
+/// such as thousands separators.
+///
+/// Created by [`OneBased::formatted()`].
+///
+/// ```
+/// # use one_based::OneBasedU32;
+/// use num_format::Locale;
+///
+/// let v = OneBasedU32::from_one_based(1234567)?;
+/// assert_eq!(v.formatted(&Locale::en).to_string(), "1,234,567");
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+#[cfg(feature = "num-format")]
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted<'a, I, F> {
+    value: I,
+    format: &'a F,
+}
+
+#[cfg(feature = "num-format")]
+impl<I, F> Display for Formatted<'_, I, F>
+where
+    I: OneBasedInteger,
+    I::Int: num_format::ToFormattedStr,
+    F: num_format::Format,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buf = num_format::Buffer::new();
+        buf.write_formatted(&self.value.as_one_based().get(), self.format);
+        f.pad(buf.as_str())
+    }
+}
+
+#[cfg(feature = "num-format")]
+impl<T: UnsignedNonZero> OneBased<T>
+where
+    Self: OneBasedInteger,
+    <Self as OneBasedInteger>::Int: num_format::ToFormattedStr,
+{
+    /// Returns an adapter to display the 1-based value with the given [`num_format::Format`],
+    /// such as [`num_format::Locale`].
+    #[inline]
+    pub fn formatted<'a, F: num_format::Format>(&self, format: &'a F) -> Formatted<'a, Self, F> {
+        Formatted {
+            value: *self,
+            format,
+        }
+    }
+}
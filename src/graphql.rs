@@ -0,0 +1,73 @@
+//! Provides [`async_graphql`] integration.
+//!
+//! `OneBased*` types are exposed as GraphQL `Int` holding the 1-based value,
+//! and input of 0, negative or too large value is rejected.
+//!
+//! ```
+//! # use one_based::OneBasedU32;
+//! use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn offset(&self, page: OneBasedU32) -> u32 {
+//!         page.as_zero_based() * 10
+//!     }
+//! }
+//!
+//! let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//! let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+//! let res = rt.block_on(schema.execute("{ offset(page: 2) }"));
+//! assert_eq!(res.data.to_string(), "{offset: 10}");
+//!
+//! let res = rt.block_on(schema.execute("{ offset(page: 0) }"));
+//! assert!(res.errors[0].message.contains("1-based index must be >= 1"));
+//! ```
+
+use ::async_graphql::{InputValueError, InputValueResult, Number, Scalar, ScalarType, Value};
+
+use crate::{
+    OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize, ZERO_DESERIALIZE_MESSAGE,
+};
+
+macro_rules! impl_scalar {
+    ($($name:ident, $itype:ty);+) => {$(
+        /// The `Int` scalar type represents 1-based index.
+        #[Scalar(name = "Int")]
+        impl ScalarType for $name {
+            fn parse(value: Value) -> InputValueResult<Self> {
+                let n = match &value {
+                    Value::Number(n) => n,
+                    _ => return Err(InputValueError::expected_type(value)),
+                };
+                match n.as_u64() {
+                    Some(0) => Err(InputValueError::custom(ZERO_DESERIALIZE_MESSAGE)),
+                    Some(v) if v <= <$itype>::MAX as u64 => {
+                        Ok(<$name>::from_one_based(v as $itype).expect("must be non-zero"))
+                    }
+                    _ => Err(InputValueError::custom(format!(
+                        "Only integers from 1 to {} are accepted as 1-based index.",
+                        <$itype>::MAX
+                    ))),
+                }
+            }
+
+            fn is_valid(value: &Value) -> bool {
+                matches!(value, Value::Number(n) if n.is_u64())
+            }
+
+            fn to_value(&self) -> Value {
+                Value::Number(Number::from(self.as_one_based().get() as u64))
+            }
+        }
+    )*};
+}
+
+impl_scalar!(
+    OneBasedU8, u8;
+    OneBasedU16, u16;
+    OneBasedU32, u32;
+    OneBasedU64, u64;
+    OneBasedUsize, usize
+);
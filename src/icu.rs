@@ -0,0 +1,141 @@
+//! Provides locale-aware ordinal formatting with [`icu_plurals`].
+//!
+//! ICU4X ships the CLDR ordinal plural rules, which tell which category
+//! (such as "one", "two" or "other") the number belongs to,
+//! but not the ordinal suffix strings themselves.
+//! [`OrdinalSuffixes`] fills that gap by mapping each category into the suffix.
+
+use core::fmt::Display;
+
+use icu_plurals::{PluralCategory, PluralOperands, PluralRules};
+
+use crate::{OneBased, OneBasedInteger, UnsignedNonZero};
+
+/// Ordinal suffix for each plural category.
+///
+/// Use with [`PluralRules`] created by `PluralRules::try_new_ordinal()`,
+/// so that the category reflects the ordinal rules of the locale.
+/// Predefined values are provided for a few locales,
+/// and you can also construct one from your own translation catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrdinalSuffixes<'a> {
+    /// Suffix for [`PluralCategory::Zero`].
+    pub zero: &'a str,
+    /// Suffix for [`PluralCategory::One`].
+    pub one: &'a str,
+    /// Suffix for [`PluralCategory::Two`].
+    pub two: &'a str,
+    /// Suffix for [`PluralCategory::Few`].
+    pub few: &'a str,
+    /// Suffix for [`PluralCategory::Many`].
+    pub many: &'a str,
+    /// Suffix for [`PluralCategory::Other`].
+    pub other: &'a str,
+}
+
+impl<'a> OrdinalSuffixes<'a> {
+    /// Suffixes for English, such as "1st", "2nd", "3rd" and "4th".
+    pub const ENGLISH: OrdinalSuffixes<'static> = OrdinalSuffixes {
+        zero: "th",
+        one: "st",
+        two: "nd",
+        few: "rd",
+        many: "th",
+        other: "th",
+    };
+
+    /// Suffixes for French, such as "1er" and "2e".
+    pub const FRENCH: OrdinalSuffixes<'static> = OrdinalSuffixes::uniform("e").with_one("er");
+
+    /// Suffixes for German, such as "1." and "2.".
+    pub const GERMAN: OrdinalSuffixes<'static> = OrdinalSuffixes::uniform(".");
+
+    /// Creates `OrdinalSuffixes` using the same suffix for all categories.
+    pub const fn uniform(suffix: &'a str) -> Self {
+        Self {
+            zero: suffix,
+            one: suffix,
+            two: suffix,
+            few: suffix,
+            many: suffix,
+            other: suffix,
+        }
+    }
+
+    /// Returns `OrdinalSuffixes` with the suffix for [`PluralCategory::One`] replaced.
+    pub const fn with_one(self, suffix: &'a str) -> Self {
+        Self {
+            one: suffix,
+            ..self
+        }
+    }
+
+    /// Returns the suffix for the given category.
+    pub const fn get(&self, category: PluralCategory) -> &'a str {
+        match category {
+            PluralCategory::Zero => self.zero,
+            PluralCategory::One => self.one,
+            PluralCategory::Two => self.two,
+            PluralCategory::Few => self.few,
+            PluralCategory::Many => self.many,
+            PluralCategory::Other => self.other,
+        }
+    }
+}
+
+/// Display adapter, which formats the 1-based value with locale-aware ordinal suffix.
+///
+/// Created by [`OneBased::localized_ordinal()`].
+///
+/// ```
+/// # use one_based::{OneBasedU32, OrdinalSuffixes};
+/// use icu_locid::locale;
+/// use icu_plurals::PluralRules;
+///
+/// let rules = PluralRules::try_new_ordinal(&locale!("fr").into()).unwrap();
+/// let first = OneBasedU32::from_one_based(1)?;
+/// let second = OneBasedU32::from_one_based(2)?;
+/// assert_eq!(first.localized_ordinal(&rules, &OrdinalSuffixes::FRENCH).to_string(), "1er");
+/// assert_eq!(second.localized_ordinal(&rules, &OrdinalSuffixes::FRENCH).to_string(), "2e");
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LocalizedOrdinal<'a, I> {
+    value: I,
+    suffix: &'a str,
+}
+
+impl<I: OneBasedInteger> Display for LocalizedOrdinal<'_, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}{}", self.value, self.suffix)
+    }
+}
+
+impl<T: UnsignedNonZero> OneBased<T>
+where
+    Self: OneBasedInteger,
+    <Self as OneBasedInteger>::Int: Into<PluralOperands>,
+{
+    /// Returns the plural category of the 1-based value under the given rules.
+    ///
+    /// Pass [`PluralRules`] created by `PluralRules::try_new_ordinal()`
+    /// to obtain the ordinal category.
+    #[inline]
+    pub fn plural_category(&self, rules: &PluralRules) -> PluralCategory {
+        rules.category_for(self.as_one_based().get())
+    }
+
+    /// Returns an adapter to display the 1-based value with the ordinal suffix
+    /// chosen by the given ordinal [`PluralRules`].
+    #[inline]
+    pub fn localized_ordinal<'a>(
+        &self,
+        rules: &PluralRules,
+        suffixes: &OrdinalSuffixes<'a>,
+    ) -> LocalizedOrdinal<'a, Self> {
+        LocalizedOrdinal {
+            value: *self,
+            suffix: suffixes.get(self.plural_category(rules)),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Provides iterator adapters yielding `OneBasedUsize` positions.
+
+use core::iter::FusedIterator;
+
+use crate::OneBasedUsize;
+
+/// Extension trait of iterators to deal with 1-based positions.
+pub trait IteratorExt: Iterator {
+    /// Returns the iterator yielding the 1-based position along with the element,
+    /// which is the 1-based version of [`Iterator::enumerate`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `usize::MAX` elements,
+    /// as the position after `usize::MAX` can't be represented.
+    ///
+    /// ```
+    /// # use one_based::IteratorExt;
+    /// let items = ["apple", "banana"];
+    /// let numbered: Vec<String> = items
+    ///     .iter()
+    ///     .enumerate_one_based()
+    ///     .map(|(i, item)| format!("{}. {}", i, item))
+    ///     .collect();
+    /// assert_eq!(numbered, ["1. apple", "2. banana"]);
+    /// ```
+    #[inline]
+    fn enumerate_one_based(self) -> EnumerateOneBased<Self>
+    where
+        Self: Sized,
+    {
+        EnumerateOneBased {
+            iter: self,
+            count: 0,
+        }
+    }
+
+    /// Searches for the element satisfying the predicate, and returns its 1-based position.
+    /// This is the 1-based version of [`Iterator::position`].
+    ///
+    /// ```
+    /// # use one_based::IteratorExt;
+    /// let mut lines = ["ok", "ok", "error", "ok"].iter();
+    /// let found = lines.position_one_based(|line| *line == "error");
+    /// assert_eq!(found.map(|i| i.get()), Some(3));
+    /// ```
+    #[inline]
+    fn position_one_based<P>(&mut self, predicate: P) -> Option<OneBasedUsize>
+    where
+        Self: Sized,
+        P: FnMut(Self::Item) -> bool,
+    {
+        self.position(predicate).map(position)
+    }
+
+    /// Searches for the element satisfying the predicate from the back,
+    /// and returns its 1-based position counted from the front.
+    /// This is the 1-based version of [`Iterator::rposition`].
+    ///
+    /// ```
+    /// # use one_based::IteratorExt;
+    /// let mut lines = ["ok", "error", "error", "ok"].iter();
+    /// let found = lines.rposition_one_based(|line| *line == "error");
+    /// assert_eq!(found.map(|i| i.get()), Some(3));
+    /// ```
+    #[inline]
+    fn rposition_one_based<P>(&mut self, predicate: P) -> Option<OneBasedUsize>
+    where
+        Self: Sized + ExactSizeIterator + DoubleEndedIterator,
+        P: FnMut(Self::Item) -> bool,
+    {
+        self.rposition(predicate).map(position)
+    }
+
+    /// Returns the element at the given 1-based position.
+    /// This is the 1-based version of [`Iterator::nth`].
+    ///
+    /// ```
+    /// # use one_based::{IteratorExt, OneBasedUsize};
+    /// let mut items = ["apple", "banana", "cherry"].iter();
+    /// let second = OneBasedUsize::from_one_based(2)?;
+    /// assert_eq!(items.nth_one_based(second), Some(&"banana"));
+    /// # Ok::<(), one_based::OneBasedError>(())
+    /// ```
+    #[inline]
+    fn nth_one_based(&mut self, n: OneBasedUsize) -> Option<Self::Item> {
+        self.nth(n.as_zero_based())
+    }
+}
+
+impl<I: Iterator + ?Sized> IteratorExt for I {}
+
+/// Iterator yielding the 1-based position along with the element.
+///
+/// This is created by [`IteratorExt::enumerate_one_based`].
+#[derive(Debug, Clone)]
+pub struct EnumerateOneBased<I> {
+    iter: I,
+    /// The number of the elements already yielded, i.e. 0-based position of the next one.
+    count: usize,
+}
+
+const TOO_MANY_ELEMENTS: &str = "too many elements for 1-based position";
+
+/// Returns the 1-based position of the given 0-based position.
+#[inline]
+#[track_caller]
+fn position(zero_based: usize) -> OneBasedUsize {
+    OneBasedUsize::from_zero_based_opt(zero_based).expect(TOO_MANY_ELEMENTS)
+}
+
+/// Returns the 1-based position of the element `n` ahead of the 0-based position `count`.
+#[inline]
+#[track_caller]
+fn position_after(count: usize, n: usize) -> OneBasedUsize {
+    position(count.checked_add(n).expect(TOO_MANY_ELEMENTS))
+}
+
+impl<I: Iterator> Iterator for EnumerateOneBased<I> {
+    type Item = (OneBasedUsize, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let i = position(self.count);
+        self.count += 1;
+        Some((i, item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth(n)?;
+        let i = position_after(self.count, n);
+        // the 1-based position is the 0-based position of the next element.
+        self.count = i.get();
+        Some((i, item))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I> DoubleEndedIterator for EnumerateOneBased<I>
+where
+    I: ExactSizeIterator + DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        // the remaining length is counted after taking the last element.
+        Some((position_after(self.count, self.iter.len()), item))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for EnumerateOneBased<I> {}
+
+impl<I: FusedIterator> FusedIterator for EnumerateOneBased<I> {}
@@ -0,0 +1,115 @@
+//! Provides [`schemars`] integration.
+//!
+//! `OneBased*` types are described as integer with `minimum` 1,
+//! and `maximum` of the underlying type.
+//! `maximum` is omitted for `OneBasedU128`, as JSON Schema can't hold `u128::MAX` exactly.
+
+use alloc::borrow::Cow;
+
+use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{
+    MaybeOneBasedU128, MaybeOneBasedU16, MaybeOneBasedU32, MaybeOneBasedU64, MaybeOneBasedU8,
+    MaybeOneBasedUsize, OneBasedStoredZeroU128, OneBasedStoredZeroU16, OneBasedStoredZeroU32,
+    OneBasedStoredZeroU64, OneBasedStoredZeroU8, OneBasedStoredZeroUsize, OneBasedU128,
+    OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize,
+};
+
+macro_rules! impl_json_schema {
+    ($name:ident, $stored_zero:ident, $maybe:ident, $itype:ty, $format:literal $(, $max:expr)?) => {
+        impl JsonSchema for $name {
+            fn inline_schema() -> bool {
+                true
+            }
+
+            fn schema_name() -> Cow<'static, str> {
+                stringify!($name).into()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                json_schema!({
+                    "type": "integer",
+                    "format": $format,
+                    "minimum": 1,
+                    $("maximum": $max,)?
+                })
+            }
+        }
+
+        impl JsonSchema for $stored_zero {
+            fn inline_schema() -> bool {
+                true
+            }
+
+            fn schema_name() -> Cow<'static, str> {
+                stringify!($stored_zero).into()
+            }
+
+            fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+                <$name>::json_schema(generator)
+            }
+        }
+
+        impl JsonSchema for $maybe {
+            fn inline_schema() -> bool {
+                true
+            }
+
+            fn schema_name() -> Cow<'static, str> {
+                stringify!($maybe).into()
+            }
+
+            fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+                <$itype>::json_schema(generator)
+            }
+        }
+    };
+}
+
+impl_json_schema!(
+    OneBasedU8,
+    OneBasedStoredZeroU8,
+    MaybeOneBasedU8,
+    u8,
+    "uint8",
+    u8::MAX
+);
+impl_json_schema!(
+    OneBasedU16,
+    OneBasedStoredZeroU16,
+    MaybeOneBasedU16,
+    u16,
+    "uint16",
+    u16::MAX
+);
+impl_json_schema!(
+    OneBasedU32,
+    OneBasedStoredZeroU32,
+    MaybeOneBasedU32,
+    u32,
+    "uint32",
+    u32::MAX
+);
+impl_json_schema!(
+    OneBasedU64,
+    OneBasedStoredZeroU64,
+    MaybeOneBasedU64,
+    u64,
+    "uint64",
+    u64::MAX
+);
+impl_json_schema!(
+    OneBasedU128,
+    OneBasedStoredZeroU128,
+    MaybeOneBasedU128,
+    u128,
+    "uint128"
+);
+impl_json_schema!(
+    OneBasedUsize,
+    OneBasedStoredZeroUsize,
+    MaybeOneBasedUsize,
+    usize,
+    "uint",
+    usize::MAX
+);
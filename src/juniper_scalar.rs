@@ -0,0 +1,82 @@
+//! Provides [`juniper`] integration.
+//!
+//! `OneBased*` types are exposed as GraphQL `Int` holding the 1-based value,
+//! and input of 0, negative or too large value is rejected.
+//! As GraphQL `Int` is a signed 32-bit integer, the 1-based values larger than `i32::MAX`
+//! are returned as `Float`.
+//!
+//! ```
+//! # use one_based::OneBasedU32;
+//! use juniper::{graphql_object, graphql_value, EmptyMutation, EmptySubscription, RootNode};
+//!
+//! struct Query;
+//!
+//! #[graphql_object]
+//! impl Query {
+//!     fn offset(page: OneBasedU32) -> i32 {
+//!         page.as_zero_based() as i32 * 10
+//!     }
+//! }
+//!
+//! let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+//! let (res, _) = juniper::execute_sync("{ offset(page: 2) }", None, &schema, &Default::default(), &())
+//!     .unwrap();
+//! assert_eq!(res, graphql_value!({"offset": 10}));
+//!
+//! let (_, errors) = juniper::execute_sync("{ offset(page: 0) }", None, &schema, &Default::default(), &())
+//!     .unwrap();
+//! assert!(errors[0].error().message().contains("1-based index must be >= 1"));
+//! ```
+
+use core::convert::TryFrom;
+
+use ::juniper::{graphql_scalar, InputValue, ScalarValue, Value};
+
+use crate::{
+    OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize, ZERO_DESERIALIZE_MESSAGE,
+};
+
+macro_rules! impl_scalar {
+    ($($name:ident, $alias:ident, $with:ident, $itype:ty);+) => {$(
+        /// The `Int` scalar type represents 1-based index.
+        #[graphql_scalar]
+        #[graphql(name = "Int", with = $with, parse_token(i32))]
+        // Alias to define the scalar for the type declared elsewhere.
+        #[allow(dead_code)]
+        type $alias = $name;
+
+        mod $with {
+            use super::*;
+
+            pub(super) fn to_output<S: ScalarValue>(v: &$name) -> Value<S> {
+                let v = v.as_one_based().get();
+                match i32::try_from(v) {
+                    Ok(v) => Value::scalar(v),
+                    Err(_) => Value::scalar(v as f64),
+                }
+            }
+
+            pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<$name, String> {
+                let n = v
+                    .as_int_value()
+                    .ok_or_else(|| format!("Expected `Int`, found: {}", v))?;
+                match <$itype>::try_from(n) {
+                    Ok(0) => Err(ZERO_DESERIALIZE_MESSAGE.to_string()),
+                    Ok(v) => Ok(<$name>::from_one_based(v).expect("must be non-zero")),
+                    Err(_) => Err(format!(
+                        "Only integers from 1 to {} are accepted as 1-based index.",
+                        <$itype>::MAX.min(i32::MAX as $itype)
+                    )),
+                }
+            }
+        }
+    )*};
+}
+
+impl_scalar!(
+    OneBasedU8, OneBasedU8Scalar, one_based_u8, u8;
+    OneBasedU16, OneBasedU16Scalar, one_based_u16, u16;
+    OneBasedU32, OneBasedU32Scalar, one_based_u32, u32;
+    OneBasedU64, OneBasedU64Scalar, one_based_u64, u64;
+    OneBasedUsize, OneBasedUsizeScalar, one_based_usize, usize
+);
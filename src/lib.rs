@@ -24,6 +24,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+extern crate alloc;
+
 use core::{
     fmt::Display,
     num::{
@@ -32,20 +35,684 @@ use core::{
     str::FromStr,
 };
 
-trait OneBased {
-    type IntType;
-    type NonZeroType;
+#[cfg(feature = "rkyv")]
+mod archive;
+mod atomic;
+#[cfg(feature = "std")]
+mod bit_set;
+#[cfg(feature = "clap")]
+mod cli;
+#[cfg(feature = "english")]
+mod english;
+#[cfg(feature = "sea-orm")]
+mod entity;
+#[cfg(feature = "fake")]
+mod faker;
+pub mod ffi;
+#[cfg(feature = "std")]
+mod field;
+mod fmt;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "icu")]
+mod icu;
+mod iter;
+#[cfg(feature = "schemars")]
+mod json_schema;
+#[cfg(feature = "juniper")]
+mod juniper_scalar;
+#[cfg(feature = "std")]
+mod lines;
+mod locator;
+#[cfg(feature = "mlua")]
+mod lua;
+mod maybe;
+#[cfg(feature = "bson")]
+mod mongo;
+#[cfg(feature = "num-traits")]
+mod num;
+#[cfg(feature = "utoipa")]
+mod openapi;
+#[cfg(feature = "diesel")]
+mod orm;
+#[cfg(feature = "rayon")]
+mod par;
+mod parse;
+#[cfg(feature = "postgres-types")]
+mod pg;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "prost")]
+mod proto;
+#[cfg(feature = "pyo3")]
+mod python;
+#[cfg(feature = "rand")]
+mod random;
+mod range;
+#[cfg(feature = "std")]
+mod range_set;
+#[cfg(feature = "roman")]
+mod roman;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod slice;
+#[cfg(feature = "sqlx")]
+mod sql;
+#[cfg(feature = "rusqlite")]
+mod sqlite;
+mod stored_zero;
+mod varint;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+#[cfg(feature = "rkyv")]
+pub use archive::ArchivedOneBased;
+#[cfg(target_has_atomic = "32")]
+pub use atomic::AtomicOneBasedU32;
+#[cfg(target_has_atomic = "64")]
+pub use atomic::{AtomicOneBasedU64, CounterOverflow, OneBasedCounter};
+#[cfg(feature = "std")]
+pub use bit_set::{BitSetIter, OneBasedBitSet};
+#[cfg(feature = "clap")]
+pub use cli::OneBasedValueParser;
+#[cfg(feature = "english")]
+pub use english::ParseWordsError;
+#[cfg(feature = "std")]
+pub use field::{FieldIndices, FieldList, ParseFieldListError};
+#[cfg(feature = "num-format")]
+pub use fmt::Formatted;
+pub use fmt::{DebugBoth, DisplayZeroBased, FormatBuffer, Ordinal};
+#[cfg(feature = "icu")]
+pub use icu::{LocalizedOrdinal, OrdinalSuffixes};
+pub use iter::{EnumerateOneBased, IteratorExt};
+#[cfg(feature = "std")]
+pub use lines::{byte_lines_numbered, lines_numbered, ByteLinesNumbered, LinesNumbered};
+pub use locator::{Locator, ParseLocatorError};
+pub use maybe::{
+    MaybeOneBasedU128, MaybeOneBasedU16, MaybeOneBasedU32, MaybeOneBasedU64, MaybeOneBasedU8,
+    MaybeOneBasedUsize,
+};
+#[cfg(feature = "bson")]
+pub use mongo::FromBsonError;
+#[cfg(feature = "rayon")]
+pub use par::{IndexedParallelIteratorExt, ParEnumerateOneBased};
+#[cfg(feature = "std")]
+pub use parse::ParseOneBasedOsError;
+pub use parse::{ParseOneBasedError, ParseOneBasedErrorKind, ParseOrdinalError};
+#[cfg(feature = "rand")]
+pub use random::UniformOneBased;
+pub use range::{CheckedIterFrom, OneBasedRange, OneBasedStepBy, ParseRangeError};
+#[cfg(feature = "std")]
+pub use range_set::{OneBasedRangeSet, RangeSetRanges};
+#[cfg(feature = "roman")]
+pub use roman::{ParseRomanError, Roman};
+pub use slice::{IndexOneBased, OneBasedSliceExt};
+pub use stored_zero::{
+    OneBasedStoredZeroU128, OneBasedStoredZeroU16, OneBasedStoredZeroU32, OneBasedStoredZeroU64,
+    OneBasedStoredZeroU8, OneBasedStoredZeroUsize,
+};
+pub use varint::VarintError;
+#[cfg(feature = "wasm")]
+pub use wasm::FromJsValueError;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// NonZero unsigned integer types, which can be used as the representation of [`OneBased`].
+///
+/// This trait is sealed and implemented for all `NonZeroU*` types.
+pub trait UnsignedNonZero:
+    sealed::Sealed
+    + Copy
+    + Eq
+    + Ord
+    + core::hash::Hash
+    + core::fmt::Debug
+    + Display
+    + core::fmt::LowerHex
+    + core::fmt::UpperHex
+    + core::fmt::Octal
+    + core::fmt::Binary
+    + FromStr<Err = ParseIntError>
+{
+    /// Underlying unsigned integer type.
+    type Int: Copy + Eq + Ord + core::hash::Hash + core::fmt::Debug + Display;
+
+    /// The largest value.
+    const MAX: Self;
+
+    /// Creates the value, or returns `None` if the given value is zero.
+    fn new(v: Self::Int) -> Option<Self>;
+
+    /// Returns the underlying integer.
+    fn get(self) -> Self::Int;
+}
+
+macro_rules! impl_unsigned_nonzero {
+    ($($nonzerotype:ty, $itype:ty);+) => {$(
+        impl sealed::Sealed for $nonzerotype {}
+
+        impl UnsignedNonZero for $nonzerotype {
+            type Int = $itype;
+
+            const MAX: Self = <$nonzerotype>::MAX;
+
+            #[inline]
+            fn new(v: Self::Int) -> Option<Self> {
+                <$nonzerotype>::new(v)
+            }
+
+            #[inline]
+            fn get(self) -> Self::Int {
+                <$nonzerotype>::get(self)
+            }
+        }
+    )*};
+}
+
+impl_unsigned_nonzero!(
+    NonZeroU8, u8;
+    NonZeroU16, u16;
+    NonZeroU32, u32;
+    NonZeroU64, u64;
+    NonZeroU128, u128;
+    NonZeroUsize, usize
+);
+
+/// Represents 1-based index, backed by one of `NonZeroU*` types.
+///
+/// To describe configuration by humans, often 1-based index is easier than 0-based to understand.
+/// On the other hand, 0-based index is easier to use in the programming.
+/// Also, it's quite hard to track if the index is 0-based or 1-based.
+/// `OneBased` provides ergonomics to handle user provided 1-baed index safely.
+///
+/// Usually you'd use the type aliases such as [`OneBasedU32`],
+/// and use `OneBased<T>` only to write code generic over the index width.
+///
+/// `OneBased<T>` is guaranteed to have the same layout as `T`, as it's `#[repr(transparent)]`.
+///
+/// With `serde` feature, `OneBased<T>` is serialized as the bare unsigned integer of the 1-based value,
+/// in both human-readable and compact formats, without newtype struct wrapping.
+/// See [`serde`](crate::serde) module for the alternative representations.
+///
+/// ```
+/// # use one_based::{OneBased, OneBasedU8, OneBasedU64, UnsignedNonZero};
+/// fn describe<T: UnsignedNonZero>(v: OneBased<T>) -> String {
+///     format!("#{}", v)
+/// }
+///
+/// assert_eq!(describe(OneBasedU8::from_one_based(3)?), "#3");
+/// assert_eq!(describe(OneBasedU64::from_zero_based(3)?), "#4");
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::TryFromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct OneBased<T: UnsignedNonZero>(T);
+
+// SAFETY: OneBased<T> is repr(transparent) over T.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: UnsignedNonZero> bytemuck::TransparentWrapper<T> for OneBased<T> {}
+
+// SAFETY: OneBased<T> is repr(transparent) over T, and has exactly the same validity.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: UnsignedNonZero + bytemuck::CheckedBitPattern> bytemuck::CheckedBitPattern
+    for OneBased<T>
+{
+    type Bits = T::Bits;
+
+    #[inline]
+    fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+        T::is_valid_bit_pattern(bits)
+    }
+}
+
+// SAFETY: OneBased<T> is repr(transparent) over T.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: UnsignedNonZero + bytemuck::NoUninit> bytemuck::NoUninit for OneBased<T> {}
+
+#[cfg(feature = "defmt")]
+impl<T: UnsignedNonZero + defmt::Format> defmt::Format for OneBased<T> {
+    /// Emits the 1-based value.
+    fn format(&self, f: defmt::Formatter<'_>) {
+        self.0.format(f)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<T: UnsignedNonZero> ufmt::uDisplay for OneBased<T>
+where
+    T::Int: ufmt::uDisplay,
+{
+    /// Writes the 1-based value.
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        ufmt::uDisplay::fmt(&self.0.get(), f)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<T: UnsignedNonZero> ufmt::uDebug for OneBased<T>
+where
+    T::Int: ufmt::uDebug,
+{
+    /// Writes the 1-based value.
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        ufmt::uDebug::fmt(&self.0.get(), f)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for OneBased<T>
+where
+    T: UnsignedNonZero,
+    Self: OneBasedInteger,
+    <Self as OneBasedInteger>::Int: arbitrary::Arbitrary<'a> + arbitrary::unstructured::Int,
+{
+    /// Generates only the valid values `1..=MAX`, without wasting the input on 0.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let v =
+            u.int_in_range(Self::FIRST.as_one_based().get()..=Self::MAX.as_one_based().get())?;
+        Ok(Self::from_one_based_opt(v).expect("must be non-zero"))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <<Self as OneBasedInteger>::Int as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<T: UnsignedNonZero + bincode::Encode> bincode::Encode for OneBased<T> {
+    /// Encodes the 1-based value.
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        self.0.encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<Context, T: UnsignedNonZero + bincode::Decode<Context>> bincode::Decode<Context>
+    for OneBased<T>
+{
+    /// Decodes the 1-based value, and rejects 0.
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        T::decode(decoder).map(Self)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, Context, T: UnsignedNonZero + bincode::BorrowDecode<'de, Context>>
+    bincode::BorrowDecode<'de, Context> for OneBased<T>
+{
+    /// Decodes the 1-based value, and rejects 0.
+    fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        T::borrow_decode(decoder).map(Self)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T> borsh::BorshSerialize for OneBased<T>
+where
+    T: UnsignedNonZero,
+    T::Int: borsh::BorshSerialize,
+{
+    /// Serializes the 1-based value.
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.0.get().serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T> borsh::BorshDeserialize for OneBased<T>
+where
+    T: UnsignedNonZero,
+    T::Int: borsh::BorshDeserialize,
+{
+    /// Deserializes the 1-based value, and rejects 0 with [`borsh::io::ErrorKind::InvalidData`].
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        T::new(T::Int::deserialize_reader(reader)?)
+            .map(Self)
+            .ok_or_else(|| {
+                borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, ZERO_DESERIALIZE_MESSAGE)
+            })
+    }
+}
+
+#[cfg(feature = "musli")]
+impl<M, T> musli::Encode<M> for OneBased<T>
+where
+    T: UnsignedNonZero,
+    T::Int: musli::Encode<M>,
+{
+    type Encode = Self;
+
+    /// Encodes the 1-based value.
+    fn encode<E: musli::Encoder<Mode = M>>(&self, encoder: E) -> Result<(), E::Error> {
+        self.0.get().encode(encoder)
+    }
+
+    fn as_encode(&self) -> &Self::Encode {
+        self
+    }
+}
+
+#[cfg(feature = "musli")]
+impl<'de, M, A, T> musli::Decode<'de, M, A> for OneBased<T>
+where
+    A: musli::Allocator,
+    T: UnsignedNonZero,
+    T::Int: musli::Decode<'de, M, A>,
+{
+    /// Decodes the 1-based value, and rejects 0 with the descriptive error.
+    fn decode<D: musli::Decoder<'de, Mode = M, Allocator = A>>(
+        decoder: D,
+    ) -> Result<Self, D::Error> {
+        use musli::Context as _;
+
+        let cx = decoder.cx();
+        T::new(decoder.decode()?)
+            .map(Self)
+            .ok_or_else(|| cx.message(ZERO_DESERIALIZE_MESSAGE))
+    }
+}
+
+#[cfg(feature = "minicbor")]
+impl<C, T> minicbor::Encode<C> for OneBased<T>
+where
+    T: UnsignedNonZero,
+    T::Int: minicbor::Encode<C>,
+{
+    /// Encodes the 1-based value as CBOR unsigned integer.
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        ctx: &mut C,
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        self.0.get().encode(e, ctx)
+    }
+}
+
+#[cfg(feature = "minicbor")]
+impl<C, T> minicbor::CborLen<C> for OneBased<T>
+where
+    T: UnsignedNonZero,
+    T::Int: minicbor::CborLen<C>,
+{
+    fn cbor_len(&self, ctx: &mut C) -> usize {
+        self.0.get().cbor_len(ctx)
+    }
+}
+
+#[cfg(feature = "minicbor")]
+impl<'b, C, T> minicbor::Decode<'b, C> for OneBased<T>
+where
+    T: UnsignedNonZero,
+    T::Int: minicbor::Decode<'b, C>,
+{
+    /// Decodes the 1-based value, and rejects 0.
+    fn decode(d: &mut minicbor::Decoder<'b>, ctx: &mut C) -> Result<Self, minicbor::decode::Error> {
+        let p = d.position();
+        T::new(T::Int::decode(d, ctx)?)
+            .map(Self)
+            .ok_or_else(|| minicbor::decode::Error::message(ZERO_DESERIALIZE_MESSAGE).at(p))
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl<T> postcard::experimental::max_size::MaxSize for OneBased<T>
+where
+    T: UnsignedNonZero + postcard::experimental::max_size::MaxSize,
+{
+    const POSTCARD_MAX_SIZE: usize = T::POSTCARD_MAX_SIZE;
+}
+
+#[cfg(feature = "redis")]
+impl<T> redis::ToRedisArgs for OneBased<T>
+where
+    T: UnsignedNonZero + redis::ToRedisArgs,
+{
+    /// Writes the 1-based value as Redis integer.
+    fn write_redis_args<W: ?Sized + redis::RedisWrite>(&self, out: &mut W) {
+        self.0.write_redis_args(out)
+    }
+
+    fn describe_numeric_behavior(&self) -> redis::NumericBehavior {
+        self.0.describe_numeric_behavior()
+    }
+}
+
+#[cfg(feature = "redis")]
+impl<T> redis::FromRedisValue for OneBased<T>
+where
+    T: UnsignedNonZero,
+    T::Int: redis::FromRedisValue + core::convert::TryFrom<i64>,
+{
+    /// Reads the 1-based value from Redis integer or string,
+    /// and rejects 0, negative or too large integers with [`redis::ErrorKind::TypeError`].
+    fn from_redis_value(v: &redis::Value) -> redis::RedisResult<Self> {
+        let v = match *v {
+            // redis casts integers with `as`, which wraps negative values.
+            redis::Value::Int(i) => {
+                <T::Int as core::convert::TryFrom<i64>>::try_from(i).map_err(|_| {
+                    (
+                        redis::ErrorKind::TypeError,
+                        "integer out of range for 1-based index",
+                    )
+                })?
+            }
+            _ => T::Int::from_redis_value(v)?,
+        };
+        T::new(v)
+            .map(Self)
+            .ok_or_else(|| (redis::ErrorKind::TypeError, ZERO_DESERIALIZE_MESSAGE).into())
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<'a, C, T> speedy::Readable<'a, C> for OneBased<T>
+where
+    C: speedy::Context,
+    T: UnsignedNonZero + speedy::Readable<'a, C>,
+{
+    /// Reads the 1-based value in the fixed-width layout, and rejects 0.
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        T::read_from(reader).map(Self)
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        T::minimum_bytes_needed()
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<C, T> speedy::Writable<C> for OneBased<T>
+where
+    C: speedy::Context,
+    T: UnsignedNonZero + speedy::Writable<C>,
+{
+    /// Writes the 1-based value in the fixed-width layout.
+    fn write_to<W: ?Sized + speedy::Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        self.0.write_to(writer)
+    }
+
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        self.0.bytes_needed()
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T> quickcheck::Arbitrary for OneBased<T>
+where
+    T: UnsignedNonZero + 'static,
+    Self: OneBasedInteger,
+    <Self as OneBasedInteger>::Int: quickcheck::Arbitrary,
+{
+    /// Generates from the arbitrary 0-based value, so that both 1 and MAX are likely to appear.
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::from_zero_based_opt(quickcheck::Arbitrary::arbitrary(g)).unwrap_or(Self::MAX)
+    }
+
+    /// Shrinks toward 1.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            quickcheck::Arbitrary::shrink(&self.as_zero_based())
+                .filter_map(Self::from_zero_based_opt),
+        )
+    }
+}
+
+impl<T: UnsignedNonZero> sealed::Sealed for OneBased<T> {}
+
+/// Common interface of all `OneBased*` types, to write code generic over the index width.
+///
+/// This trait is sealed and implemented for all `OneBased*` types.
+/// The methods are same as the inherent ones, which are preferred for non-generic code.
+///
+/// ```
+/// # use one_based::{OneBasedInteger, OneBasedU8, OneBasedU64};
+/// fn render<I: OneBasedInteger>(idx: I) -> String {
+///     format!("{} (0-based: {})", idx, idx.as_zero_based())
+/// }
+///
+/// assert_eq!(render(OneBasedU8::FIRST), "1 (0-based: 0)");
+/// assert_eq!(render(OneBasedU64::from_one_based(5)?), "5 (0-based: 4)");
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+pub trait OneBasedInteger:
+    sealed::Sealed
+    + Copy
+    + Eq
+    + Ord
+    + core::hash::Hash
+    + core::fmt::Debug
+    + Display
+    + FromStr<Err = ParseIntError>
+{
+    /// Underlying unsigned integer type.
+    type Int: Copy + Eq + Ord + core::hash::Hash + core::fmt::Debug + Display;
+    /// NonZero type used as 1-based representation.
+    type NonZero: UnsignedNonZero<Int = Self::Int>;
+
+    /// The first index, which is 1 in 1-based.
+    const FIRST: Self;
+    /// The largest index.
+    const MAX: Self;
+
+    /// Creates from 1-based index value.
+    /// Returns error if the given index is zero.
+    fn from_one_based(v: Self::Int) -> Result<Self, OneBasedError>;
+
+    /// Creates from 1-based index value.
+    /// Returns `None` if the given index is zero.
+    fn from_one_based_opt(v: Self::Int) -> Option<Self>;
+
+    /// Creates from 1-based index value as NonZero.
+    /// This will always succeed.
+    fn from_one_based_nonzero(v: Self::NonZero) -> Self;
+
+    /// Creates from 0-based index value.
+    /// Returns error if the given index is MAX value.
+    fn from_zero_based(v: Self::Int) -> Result<Self, OneBasedError>;
+
+    /// Creates from 0-based index value.
+    /// Returns `None` if the given index is MAX value.
+    fn from_zero_based_opt(v: Self::Int) -> Option<Self>;
+
+    /// Returns regular 0-based index.
+    fn as_zero_based(&self) -> Self::Int;
+
+    /// Returns 1-based index.
+    fn as_one_based(&self) -> Self::NonZero;
+}
+
+impl<T: UnsignedNonZero> Display for OneBased<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+macro_rules! impl_radix_fmt {
+    ($($fmt:ident),+) => {$(
+        impl<T: UnsignedNonZero> core::fmt::$fmt for OneBased<T> {
+            #[doc = concat!(r"Formats the 1-based value with [`core::fmt::", stringify!($fmt), r"`].")]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::$fmt::fmt(&self.0, f)
+            }
+        }
+    )*};
+}
+
+impl_radix_fmt!(LowerHex, UpperHex, Octal, Binary);
+
+impl<T: UnsignedNonZero> FromStr for OneBased<T> {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+/// Implements comparison between `OneBased*` and the 1-based value of `$other` type, in both directions.
+macro_rules! impl_one_based_cmp {
+    ($name:ident, $other:ty, $one_based:expr) => {
+        impl PartialEq<$other> for $name {
+            #[doc = concat!(r"Compares the 1-based value with [`", stringify!($other), r"`].")]
+            #[inline]
+            fn eq(&self, other: &$other) -> bool {
+                ($one_based)(self) == *other
+            }
+        }
+
+        impl PartialEq<$name> for $other {
+            #[doc = concat!(r"Compares with the 1-based value of [`", stringify!($name), r"`].")]
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                *self == ($one_based)(other)
+            }
+        }
+
+        impl PartialOrd<$other> for $name {
+            #[doc = concat!(r"Compares the 1-based value with [`", stringify!($other), r"`].")]
+            #[inline]
+            fn partial_cmp(&self, other: &$other) -> Option<core::cmp::Ordering> {
+                ($one_based)(self).partial_cmp(other)
+            }
+        }
+
+        impl PartialOrd<$name> for $other {
+            #[doc = concat!(r"Compares with the 1-based value of [`", stringify!($name), r"`].")]
+            #[inline]
+            fn partial_cmp(&self, other: &$name) -> Option<core::cmp::Ordering> {
+                self.partial_cmp(&($one_based)(other))
+            }
+        }
+    };
 }
 
 macro_rules! define_one_based {
     ($name:ident, $itype:ty, $nonzerotype:ty) => {
         #[doc = concat!(r" Represents 1-based index of ", stringify!($itype), r".")]
         ///
-        /// To describe configuration by humans, often 1-based index is easier than 0-based to understand.
-        /// On the other hand, 0-based index is easier to use in the programming.
-        /// Also, it's quite hard to track if the index is 0-based or 1-based.
-        /// `$name` provides ergonomics to handle user provided 1-baed index safely.
-        ///
         /// ```
         #[doc = concat!(r" # use one_based::", stringify!($name), r";")]
         #[doc = r" // Creates from 1-based index"]
@@ -57,38 +724,118 @@ macro_rules! define_one_based {
         #[doc = r" assert_eq!(v.as_one_based().get(), 1);"]
         #[doc = r" # Ok::<(), one_based::OneBasedError>(())"]
         /// ```
-        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
-        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-        pub struct $name($nonzerotype);
+        pub type $name = OneBased<$nonzerotype>;
 
-        impl OneBased for $name {
-            type IntType = $itype;
-            type NonZeroType = $nonzerotype;
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.0).finish()
+            }
         }
 
-        impl Display for $name {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                self.as_one_based().fmt(f)
+        impl OneBasedInteger for $name {
+            type Int = $itype;
+            type NonZero = $nonzerotype;
+
+            const FIRST: Self = Self::FIRST;
+            const MAX: Self = Self::MAX;
+
+            #[inline]
+            fn from_one_based(v: Self::Int) -> Result<Self, OneBasedError> {
+                Self::from_one_based(v)
+            }
+
+            #[inline]
+            fn from_one_based_opt(v: Self::Int) -> Option<Self> {
+                Self::from_one_based_opt(v)
+            }
+
+            #[inline]
+            fn from_one_based_nonzero(v: Self::NonZero) -> Self {
+                Self::from_one_based_nonzero(v)
+            }
+
+            #[inline]
+            fn from_zero_based(v: Self::Int) -> Result<Self, OneBasedError> {
+                Self::from_zero_based(v)
+            }
+
+            #[inline]
+            fn from_zero_based_opt(v: Self::Int) -> Option<Self> {
+                Self::from_zero_based_opt(v)
+            }
+
+            #[inline]
+            fn as_zero_based(&self) -> Self::Int {
+                Self::as_zero_based(self)
+            }
+
+            #[inline]
+            fn as_one_based(&self) -> Self::NonZero {
+                Self::as_one_based(self)
             }
         }
 
-        impl FromStr for $name {
-            type Err = ParseIntError;
+        impl core::convert::TryFrom<$itype> for $name {
+            type Error = OneBasedError;
+
+            #[doc = concat!(r"Attempts to convert 1-based [`", stringify!($itype), r"`] to [`", stringify!($name), r"`].")]
+            #[inline]
+            fn try_from(value: $itype) -> Result<Self, Self::Error> {
+                Self::from_one_based(value)
+            }
+        }
+
+        impl core::convert::From<$nonzerotype> for $name {
+            #[doc = concat!(r"Converts 1-based [`", stringify!($nonzerotype), r"`] to [`", stringify!($name), r"`].")]
+            #[inline]
+            fn from(value: $nonzerotype) -> Self {
+                Self::from_one_based_nonzero(value)
+            }
+        }
+
+        impl core::convert::From<$name> for $nonzerotype {
+            #[doc = concat!(r"Converts [`", stringify!($name), r"`] to 1-based [`", stringify!($nonzerotype), r"`].")]
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.as_one_based()
+            }
+        }
 
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
-                let v: $nonzerotype = s.parse()?;
-                Ok(Self::from_one_based_nonzero(v))
+        impl core::convert::From<$name> for $itype {
+            #[doc = concat!(r"Converts [`", stringify!($name), r"`] to 1-based [`", stringify!($itype), r"`].")]
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.as_one_based().get()
             }
         }
 
+        impl_one_based_cmp!($name, $itype, |v: &$name| v.as_one_based().get());
+        impl_one_based_cmp!($name, $nonzerotype, |v: &$name| v.as_one_based());
+
         impl $name {
+            /// The first index, which is 1 in 1-based.
+            pub const FIRST: Self = Self(<$nonzerotype>::MIN);
+
+            /// The largest index.
+            pub const MAX: Self = Self(<$nonzerotype>::MAX);
+
             /// Creates `$name` from 1-based index value.
             /// Returns error if the given index is zero.
             #[inline]
             pub const fn from_one_based(v: $itype) -> Result<Self, OneBasedError> {
                 match <$nonzerotype>::new(v) {
                     None => return Err(OneBasedError::ZeroIndex),
-                    Some(v) => Ok($name(v)),
+                    Some(v) => Ok(Self(v)),
+                }
+            }
+
+            /// Creates `$name` from 1-based index value.
+            /// Returns `None` if the given index is zero.
+            #[inline]
+            pub const fn from_one_based_opt(v: $itype) -> Option<Self> {
+                match <$nonzerotype>::new(v) {
+                    None => None,
+                    Some(v) => Some(Self(v)),
                 }
             }
 
@@ -99,7 +846,7 @@ macro_rules! define_one_based {
             /// Input must be greater than zero.
             #[inline]
             pub const unsafe fn from_one_based_unchecked(v: $itype) -> Self {
-                $name(<$nonzerotype>::new_unchecked(v))
+                Self(<$nonzerotype>::new_unchecked(v))
             }
 
             /// Creates `$name` from 1-based index value as [`$nonzerotype`].
@@ -115,10 +862,23 @@ macro_rules! define_one_based {
             #[inline]
             pub const fn from_zero_based(v: $itype) -> Result<Self, OneBasedError> {
                 if v == <$nonzerotype>::MAX.get() {
-                    return Err(OneBasedError::OverflowIndex);
+                    return Err(OneBasedError::OverflowIndex {
+                        value: v as u128,
+                        bits: <$itype>::BITS,
+                    });
                 }
                 // this won't overflow, and cannot be zero (note all $itype is unsigned).
-                Ok($name(unsafe { <$nonzerotype>::new_unchecked(v + 1) }))
+                Ok(Self(unsafe { <$nonzerotype>::new_unchecked(v + 1) }))
+            }
+
+            /// Creates `$name` from 0-based index value.
+            /// Returns `None` if the given index is MAX value.
+            #[inline]
+            pub const fn from_zero_based_opt(v: $itype) -> Option<Self> {
+                match <$nonzerotype>::new(v.wrapping_add(1)) {
+                    None => None,
+                    Some(v) => Some(Self(v)),
+                }
             }
 
             /// Creates `$name` from 0-based index value without check.
@@ -135,7 +895,7 @@ macro_rules! define_one_based {
             #[inline]
             pub const unsafe fn from_zero_based_unchecked(v: $itype) -> Self {
                 // this won't overflow, and cannot be zero (note all $itype is unsigned).
-                $name(unsafe { <$nonzerotype>::new_unchecked(v + 1) })
+                Self(unsafe { <$nonzerotype>::new_unchecked(v + 1) })
             }
 
             /// Returns regular 0-based index.
@@ -147,6 +907,371 @@ macro_rules! define_one_based {
             pub const fn as_one_based(&self) -> $nonzerotype {
                 self.0
             }
+
+            /// Returns 1-based index as the primitive integer.
+            #[inline]
+            pub const fn get(self) -> $itype {
+                self.0.get()
+            }
+
+            /// Returns the number of leading zeros in the binary representation of the 1-based value.
+            #[inline]
+            pub const fn leading_zeros(self) -> u32 {
+                self.0.leading_zeros()
+            }
+
+            /// Returns the number of trailing zeros in the binary representation of the 1-based value.
+            #[inline]
+            pub const fn trailing_zeros(self) -> u32 {
+                self.0.trailing_zeros()
+            }
+
+            /// Returns the number of ones in the binary representation of the 1-based value.
+            #[inline]
+            pub const fn count_ones(self) -> u32 {
+                self.0.get().count_ones()
+            }
+
+            /// Returns the number of zeros in the binary representation of the 1-based value.
+            #[inline]
+            pub const fn count_zeros(self) -> u32 {
+                self.0.get().count_zeros()
+            }
+
+            /// Returns `true` if the 1-based value is a power of two.
+            #[inline]
+            pub const fn is_power_of_two(self) -> bool {
+                self.0.is_power_of_two()
+            }
+
+            /// Returns the smallest power of two greater than or equal to the 1-based value.
+            /// Returns `None` if the result overflows.
+            #[inline]
+            pub const fn checked_next_power_of_two(self) -> Option<Self> {
+                match self.0.checked_next_power_of_two() {
+                    None => None,
+                    Some(v) => Some(Self(v)),
+                }
+            }
+
+            /// Returns the base 2 logarithm of the 1-based value, rounded down.
+            /// This never panics as the 1-based value is non-zero.
+            #[inline]
+            pub const fn ilog2(self) -> u32 {
+                self.0.ilog2()
+            }
+
+            /// Returns the base 10 logarithm of the 1-based value, rounded down.
+            /// This never panics as the 1-based value is non-zero.
+            #[inline]
+            pub const fn ilog10(self) -> u32 {
+                self.0.ilog10()
+            }
+
+            /// Returns an adapter to debug-format both 1-based and 0-based values.
+            #[inline]
+            pub const fn debug_both(&self) -> DebugBoth<Self> {
+                DebugBoth::new(stringify!($name), *self)
+            }
+
+            /// Returns an adapter to display the 1-based value with English ordinal suffix.
+            #[inline]
+            pub const fn ordinal(&self) -> Ordinal<Self> {
+                Ordinal::new(*self, (self.0.get() % 100) as u8)
+            }
+
+            /// Writes the 1-based value with English ordinal suffix into the given buffer,
+            /// and returns the written string.
+            /// Returns error if the buffer is too small.
+            ///
+            /// ```
+            #[doc = concat!(r" # use one_based::", stringify!($name), r";")]
+            #[doc = concat!(r" let v = ", stringify!($name), r"::from_one_based(12)?;")]
+            #[doc = r" let mut buf = [0u8; 8];"]
+            #[doc = r#" assert_eq!(v.format_ordinal(&mut buf), Ok("12th"));"#]
+            #[doc = r" # Ok::<(), one_based::OneBasedError>(())"]
+            /// ```
+            pub fn format_ordinal<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, core::fmt::Error> {
+                use core::fmt::Write as _;
+
+                let mut w = fmt::SliceWriter::new(buf);
+                write!(w, "{}", self.ordinal())?;
+                Ok(w.into_str())
+            }
+
+            /// Formats the 1-based value into the stack-backed [`FormatBuffer`],
+            /// without `core::fmt` machinery.
+            #[inline]
+            pub const fn to_buffer(&self) -> FormatBuffer {
+                let mut buf = [0u8; fmt::MAX_DIGITS];
+                let mut start = fmt::MAX_DIGITS;
+                let mut v = self.0.get();
+                loop {
+                    start -= 1;
+                    buf[start] = b'0' + (v % 10) as u8;
+                    v /= 10;
+                    if v == 0 {
+                        break;
+                    }
+                }
+                FormatBuffer::new(buf, start)
+            }
+
+            /// Writes the 1-based value into the given buffer, and returns the written part.
+            /// Returns error if the buffer is too small.
+            ///
+            /// ```
+            #[doc = concat!(r" # use one_based::", stringify!($name), r";")]
+            #[doc = concat!(r" let v = ", stringify!($name), r"::from_one_based(123)?;")]
+            #[doc = r" let mut buf = [0u8; 8];"]
+            #[doc = r#" assert_eq!(v.write_to(&mut buf), Ok("123"));"#]
+            #[doc = r" # Ok::<(), one_based::OneBasedError>(())"]
+            /// ```
+            pub fn write_to<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, core::fmt::Error> {
+                let formatted = self.to_buffer();
+                let dst = buf.get_mut(..formatted.len()).ok_or(core::fmt::Error)?;
+                dst.copy_from_slice(formatted.as_bytes());
+                Ok(core::str::from_utf8(dst).expect("buffer must be valid UTF-8"))
+            }
+
+            /// Parses the 1-based value in the given radix, same as the primitive `from_str_radix()`.
+            /// Returns error of [`core::num::IntErrorKind::Zero`] if the value is zero.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `radix` is not in the range from 2 to 36.
+            ///
+            /// ```
+            #[doc = concat!(r" # use one_based::", stringify!($name), r";")]
+            #[doc = concat!(r#" let v = "#, stringify!($name), r#"::from_str_radix("1f", 16)?;"#)]
+            #[doc = r" assert_eq!(v.as_zero_based(), 30);"]
+            #[doc = concat!(r#" assert!("#, stringify!($name), r#"::from_str_radix("0", 16).is_err());"#)]
+            #[doc = r" # Ok::<(), core::num::ParseIntError>(())"]
+            /// ```
+            pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+                let v = <$itype>::from_str_radix(s, radix)?;
+                Self::from_one_based_opt(v).ok_or_else(parse::zero_error)
+            }
+
+            /// Parses the textual value as 0-based index.
+            /// Returns error of [`core::num::IntErrorKind::PosOverflow`] if the value is MAX,
+            /// as that would overflow when converted to 1-based.
+            ///
+            /// ```
+            #[doc = concat!(r" # use one_based::", stringify!($name), r";")]
+            #[doc = concat!(r#" let v = "#, stringify!($name), r#"::parse_zero_based("0")?;"#)]
+            #[doc = r" assert_eq!(v.as_one_based().get(), 1);"]
+            #[doc = r" # Ok::<(), core::num::ParseIntError>(())"]
+            /// ```
+            pub fn parse_zero_based(s: &str) -> Result<Self, ParseIntError> {
+                let v: $itype = s.parse()?;
+                Self::from_zero_based_opt(v).ok_or_else(parse::overflow_error)
+            }
+
+            /// Parses the 1-based value from ASCII bytes, without UTF-8 validation.
+            /// Accepts the same syntax as [`FromStr`].
+            ///
+            /// ```
+            #[doc = concat!(r" # use one_based::", stringify!($name), r";")]
+            #[doc = concat!(r#" let v = "#, stringify!($name), r#"::from_ascii(b"42")?;"#)]
+            #[doc = r" assert_eq!(v.as_zero_based(), 41);"]
+            #[doc = r" # Ok::<(), core::num::ParseIntError>(())"]
+            /// ```
+            pub fn from_ascii(src: &[u8]) -> Result<Self, ParseIntError> {
+                let digits = match src {
+                    [] => return Err(parse::empty_error()),
+                    [b'+'] => return Err(parse::invalid_digit_error()),
+                    [b'+', rest @ ..] => rest,
+                    _ => src,
+                };
+                let mut v: $itype = 0;
+                for &c in digits {
+                    if !c.is_ascii_digit() {
+                        return Err(parse::invalid_digit_error());
+                    }
+                    v = v
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add((c - b'0') as $itype))
+                        .ok_or_else(parse::overflow_error)?;
+                }
+                Self::from_one_based_opt(v).ok_or_else(parse::zero_error)
+            }
+
+            /// Returns the 1-based value as a byte array in little-endian byte order.
+            ///
+            /// ```
+            #[doc = concat!(r" # use one_based::", stringify!($name), r";")]
+            #[doc = concat!(r" let v = ", stringify!($name), r"::from_one_based(1)?;")]
+            #[doc = r" assert_eq!(v.to_le_bytes()[0], 1);"]
+            #[doc = concat!(r" assert_eq!(", stringify!($name), r"::from_le_bytes(v.to_le_bytes()), Ok(v));")]
+            #[doc = r" # Ok::<(), one_based::OneBasedError>(())"]
+            /// ```
+            #[inline]
+            pub const fn to_le_bytes(self) -> [u8; core::mem::size_of::<$itype>()] {
+                self.as_one_based().get().to_le_bytes()
+            }
+
+            /// Returns the 1-based value as a byte array in big-endian byte order.
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; core::mem::size_of::<$itype>()] {
+                self.as_one_based().get().to_be_bytes()
+            }
+
+            /// Returns the 1-based value as a byte array in native byte order.
+            #[inline]
+            pub const fn to_ne_bytes(self) -> [u8; core::mem::size_of::<$itype>()] {
+                self.as_one_based().get().to_ne_bytes()
+            }
+
+            /// Creates `$name` from 1-based value in little-endian byte order.
+            /// Returns error if the value is zero.
+            ///
+            /// ```
+            #[doc = concat!(r" # use one_based::{", stringify!($name), r", OneBasedError};")]
+            #[doc = concat!(r" let zero = [0; core::mem::size_of::<", stringify!($name), r">()];")]
+            #[doc = concat!(r" assert_eq!(", stringify!($name), r"::from_le_bytes(zero), Err(OneBasedError::ZeroIndex));")]
+            /// ```
+            #[inline]
+            pub const fn from_le_bytes(bytes: [u8; core::mem::size_of::<$itype>()]) -> Result<Self, OneBasedError> {
+                Self::from_one_based(<$itype>::from_le_bytes(bytes))
+            }
+
+            /// Creates `$name` from 1-based value in big-endian byte order.
+            /// Returns error if the value is zero.
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; core::mem::size_of::<$itype>()]) -> Result<Self, OneBasedError> {
+                Self::from_one_based(<$itype>::from_be_bytes(bytes))
+            }
+
+            /// Creates `$name` from 1-based value in native byte order.
+            /// Returns error if the value is zero.
+            #[inline]
+            pub const fn from_ne_bytes(bytes: [u8; core::mem::size_of::<$itype>()]) -> Result<Self, OneBasedError> {
+                Self::from_one_based(<$itype>::from_ne_bytes(bytes))
+            }
+
+            /// Encodes the 1-based value as LEB128 varint into the given buffer,
+            /// and returns the written part.
+            /// Returns error if the buffer is too small.
+            ///
+            /// ```
+            #[doc = concat!(r" # use one_based::{", stringify!($name), r", VarintError};")]
+            #[doc = concat!(r" let v = ", stringify!($name), r"::from_one_based(100).unwrap();")]
+            #[doc = r" let mut buf = [0u8; 8];"]
+            #[doc = r" assert_eq!(v.to_varint(&mut buf)?, &[100]);"]
+            #[doc = concat!(r" assert_eq!(", stringify!($name), r"::from_varint(&[100, 0xff])?, (v, 1));")]
+            #[doc = concat!(r" assert_eq!(", stringify!($name), r"::from_varint(&[0]), Err(VarintError::Zero));")]
+            #[doc = r" # Ok::<(), VarintError>(())"]
+            /// ```
+            pub fn to_varint<'a>(&self, buf: &'a mut [u8]) -> Result<&'a [u8], VarintError> {
+                let len = varint::encode(self.as_one_based().get() as u128, buf)?;
+                Ok(&buf[..len])
+            }
+
+            /// Writes the 1-based value as LEB128 varint into the given writer.
+            #[cfg(feature = "std")]
+            pub fn write_varint<W: std::io::Write + ?Sized>(&self, w: &mut W) -> std::io::Result<()> {
+                let mut buf = [0u8; varint::MAX_LEN];
+                let len = varint::encode(self.as_one_based().get() as u128, &mut buf)
+                    .expect("buffer must be large enough for any integer");
+                w.write_all(&buf[..len])
+            }
+
+            /// Decodes the 1-based value from the LEB128 varint at the start of `bytes`,
+            /// and returns the value with the number of read bytes.
+            /// Returns error if the varint is truncated, too large, zero,
+            /// or has non-canonical overlong encoding such as `[0x81, 0x00]`.
+            pub fn from_varint(bytes: &[u8]) -> Result<(Self, usize), VarintError> {
+                let (v, len) = varint::decode(bytes, <$itype>::BITS)?;
+                Self::from_one_based_opt(v as $itype)
+                    .map(|v| (v, len))
+                    .ok_or(VarintError::Zero)
+            }
+
+            /// Parses the 1-based value with English ordinal suffix, such as "3rd".
+            /// The suffix is case-insensitive, and must match the number.
+            ///
+            /// ```
+            #[doc = concat!(r" # use one_based::{", stringify!($name), r", ParseOrdinalError};")]
+            #[doc = concat!(r#" let v = "#, stringify!($name), r#"::from_ordinal_str("22ND")?;"#)]
+            #[doc = r" assert_eq!(v.as_zero_based(), 21);"]
+            #[doc = r""]
+            #[doc = concat!(r#" let err = "#, stringify!($name), r#"::from_ordinal_str("2st").unwrap_err();"#)]
+            #[doc = r" assert_eq!(err, ParseOrdinalError::InvalidSuffix);"]
+            #[doc = r" # Ok::<(), ParseOrdinalError>(())"]
+            /// ```
+            pub fn from_ordinal_str(s: &str) -> Result<Self, ParseOrdinalError> {
+                let (digits, suffix) = parse::split_ordinal(s);
+                let v: Self = digits.parse()?;
+                if !v.ordinal().suffix().eq_ignore_ascii_case(suffix) {
+                    return Err(ParseOrdinalError::InvalidSuffix);
+                }
+                Ok(v)
+            }
+
+            /// Parses English cardinal or ordinal number words from "one" to "one hundred",
+            /// such as "twelve", "twenty-first" or "Ninth".
+            ///
+            /// ```
+            #[doc = concat!(r" # use one_based::", stringify!($name), r";")]
+            #[doc = concat!(r#" let v = "#, stringify!($name), r#"::from_english_words("twenty-first")?;"#)]
+            #[doc = r" assert_eq!(v.as_zero_based(), 20);"]
+            #[doc = r" # Ok::<(), one_based::ParseWordsError>(())"]
+            /// ```
+            #[cfg(feature = "english")]
+            pub fn from_english_words(s: &str) -> Result<Self, ParseWordsError> {
+                let v = english::parse_words(s)?;
+                // parsed value is always between 1 and 100.
+                Ok(unsafe { Self::from_one_based_unchecked(v as $itype) })
+            }
+
+            /// Parses the canonical Roman numeral from "I" to "MMMCMXCIX", such as "XIV".
+            /// The numeral is case-insensitive.
+            /// Returns error if the value is too large for the type.
+            ///
+            /// ```
+            #[doc = concat!(r" # use one_based::", stringify!($name), r";")]
+            #[doc = concat!(r#" let v = "#, stringify!($name), r#"::from_roman("xiv")?;"#)]
+            #[doc = r" assert_eq!(v.as_zero_based(), 13);"]
+            #[doc = r" # Ok::<(), one_based::ParseRomanError>(())"]
+            /// ```
+            #[cfg(feature = "roman")]
+            pub fn from_roman(s: &str) -> Result<Self, ParseRomanError> {
+                let v = roman::parse_roman(s)?;
+                let v: $itype =
+                    core::convert::TryFrom::try_from(v).map_err(|_| ParseRomanError)?;
+                // parsed value is never zero.
+                Ok(unsafe { Self::from_one_based_unchecked(v) })
+            }
+
+            /// Returns an adapter to display the 1-based value in upper case Roman numerals, such as "XIV".
+            #[cfg(feature = "roman")]
+            #[inline]
+            pub const fn roman(&self) -> Roman<Self> {
+                Roman::new(*self, self.0.get() as u128, false)
+            }
+
+            /// Returns an adapter to display the 1-based value in lower case Roman numerals, such as "xiv".
+            #[cfg(feature = "roman")]
+            #[inline]
+            pub const fn roman_lower(&self) -> Roman<Self> {
+                Roman::new(*self, self.0.get() as u128, true)
+            }
+
+            /// Converts into the wider `OneBased*` type `T`.
+            /// This is same as [`Into::into`], but easier to use in method chains.
+            #[inline]
+            pub fn widen<T: From<Self>>(self) -> T {
+                T::from(self)
+            }
+
+            /// Attempts to convert into the narrower `OneBased*` type `T`.
+            /// This is same as [`TryInto::try_into`](core::convert::TryInto::try_into),
+            /// but easier to use in method chains.
+            #[inline]
+            pub fn try_narrow<T: core::convert::TryFrom<Self>>(self) -> Result<T, T::Error> {
+                T::try_from(self)
+            }
         }
     };
 }
@@ -158,6 +1283,163 @@ define_one_based!(OneBasedU64, u64, NonZeroU64);
 define_one_based!(OneBasedU128, u128, NonZeroU128);
 define_one_based!(OneBasedUsize, usize, NonZeroUsize);
 
+macro_rules! impl_to_index {
+    ($($name:ty),+) => {$(
+        impl $name {
+            /// Returns 0-based index as `usize`, which can be used to index slices.
+            #[inline]
+            pub const fn to_index(&self) -> usize {
+                // this is lossless on the supported targets.
+                self.as_zero_based() as usize
+            }
+        }
+    )*};
+}
+
+impl_to_index!(OneBasedU8, OneBasedU16, OneBasedUsize);
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_to_index!(OneBasedU32);
+
+macro_rules! impl_try_to_index {
+    ($($name:ty),+) => {$(
+        impl $name {
+            /// Returns 0-based index as `usize`, which can be used to index slices.
+            /// Returns error if the index doesn't fit in `usize`.
+            #[inline]
+            pub fn try_to_index(&self) -> Result<usize, core::num::TryFromIntError> {
+                use core::convert::TryInto as _;
+                self.as_zero_based().try_into()
+            }
+        }
+    )*};
+}
+
+impl_try_to_index!(OneBasedU32, OneBasedU64, OneBasedU128);
+
+macro_rules! impl_db_i64 {
+    (@common $name:ty, $itype:ty) => {
+        impl $name {
+            /// Creates `$name` from 1-based `i64`, which is the integer type databases usually bind.
+            /// Returns error if the given value is zero, negative or too large.
+            #[inline]
+            pub const fn try_from_db_i64(v: i64) -> Result<Self, OneBasedError> {
+                if v < 0 {
+                    return Err(OneBasedError::NegativeIndex { value: v as i128 });
+                }
+                // non-negative i64 always fits in u128.
+                if v as u128 > <$itype>::MAX as u128 {
+                    return Err(OneBasedError::OutOfRangeIndex {
+                        value: v as u128,
+                        bits: <$itype>::BITS,
+                    });
+                }
+                Self::from_one_based(v as $itype)
+            }
+        }
+    };
+    (lossless: $($name:ty, $itype:ty);+) => {$(
+        impl_db_i64!(@common $name, $itype);
+
+        impl $name {
+            /// Returns 1-based index as `i64`, which is the integer type databases usually bind.
+            #[inline]
+            pub const fn as_db_i64(&self) -> i64 {
+                self.0.get() as i64
+            }
+
+            /// Returns 0-based index as `i64`, which can be used for SQL `OFFSET` clause.
+            #[inline]
+            pub const fn as_sql_offset(&self) -> i64 {
+                self.as_zero_based() as i64
+            }
+        }
+    )*};
+    (fallible: $($name:ty, $itype:ty);+) => {$(
+        impl_db_i64!(@common $name, $itype);
+
+        impl $name {
+            /// Returns 1-based index as `i64`, which is the integer type databases usually bind.
+            /// Returns error if the index doesn't fit in `i64`.
+            #[inline]
+            pub fn try_as_db_i64(&self) -> Result<i64, core::num::TryFromIntError> {
+                use core::convert::TryInto as _;
+                self.0.get().try_into()
+            }
+
+            /// Returns 0-based index as `i64`, which can be used for SQL `OFFSET` clause.
+            /// Returns error if the index doesn't fit in `i64`.
+            #[inline]
+            pub fn try_as_sql_offset(&self) -> Result<i64, core::num::TryFromIntError> {
+                use core::convert::TryInto as _;
+                self.as_zero_based().try_into()
+            }
+        }
+    )*};
+}
+
+impl_db_i64!(lossless: OneBasedU8, u8; OneBasedU16, u16; OneBasedU32, u32);
+impl_db_i64!(fallible: OneBasedU64, u64; OneBasedU128, u128; OneBasedUsize, usize);
+
+impl OneBasedUsize {
+    /// Returns the 1-based index of the last element in a collection of length `len`.
+    /// Returns `None` if the collection is empty.
+    ///
+    /// ```
+    /// # use one_based::OneBasedUsize;
+    /// let v = [10, 20, 30];
+    /// let last = OneBasedUsize::last_of(v.len()).unwrap();
+    /// assert_eq!(last.as_one_based().get(), 3);
+    /// assert_eq!(v[last.as_zero_based()], 30);
+    ///
+    /// assert_eq!(OneBasedUsize::last_of(0), None);
+    /// ```
+    #[inline]
+    pub const fn last_of(len: usize) -> Option<Self> {
+        Self::from_one_based_opt(len)
+    }
+
+    /// Creates `OneBasedUsize` from the count of a non-empty collection.
+    /// As the count equals the 1-based index of the last element, this will always succeed.
+    ///
+    /// ```
+    /// # use one_based::OneBasedUsize;
+    /// # use std::num::NonZeroUsize;
+    /// let count = NonZeroUsize::new(3).unwrap();
+    /// let last = OneBasedUsize::from_count(count);
+    /// assert_eq!(last.as_zero_based(), 2);
+    /// ```
+    #[inline]
+    pub const fn from_count(count: NonZeroUsize) -> Self {
+        Self::from_one_based_nonzero(count)
+    }
+
+    /// Returns 0-based index as `usize` if it's within a collection of length `len`.
+    /// Returns [`OneBasedError::OutOfBounds`] otherwise.
+    ///
+    /// ```
+    /// # use one_based::{OneBasedError, OneBasedUsize};
+    /// let v = [10, 20, 30];
+    /// let i = OneBasedUsize::from_one_based(3)?;
+    /// assert_eq!(v[i.index_in(v.len())?], 30);
+    ///
+    /// let i = OneBasedUsize::from_one_based(4)?;
+    /// assert_eq!(i.index_in(v.len()), Err(OneBasedError::OutOfBounds { index: 4, len: 3 }));
+    /// # Ok::<(), OneBasedError>(())
+    /// ```
+    #[inline]
+    pub const fn index_in(self, len: usize) -> Result<usize, OneBasedError> {
+        let index = self.as_zero_based();
+        if index < len {
+            Ok(index)
+        } else {
+            Err(OneBasedError::OutOfBounds {
+                index: self.as_one_based().get(),
+                len,
+            })
+        }
+    }
+}
+
 macro_rules! impl_from_one_based {
     ($source:ty => $($target:ty),+) => {$(
         impl core::convert::From<$source> for $target {
@@ -165,7 +1447,7 @@ macro_rules! impl_from_one_based {
             #[inline]
             fn from(value: $source) -> Self {
                 use core::convert::Into as _;
-                let v: <$target as OneBased>::NonZeroType = value.as_one_based().into();
+                let v: <$target as OneBasedInteger>::NonZero = value.as_one_based().into();
                 <$target>::from_one_based_nonzero(v)
             }
         }
@@ -177,6 +1459,25 @@ impl_from_one_based!(OneBasedU16 => OneBasedU32, OneBasedU64, OneBasedU128);
 impl_from_one_based!(OneBasedU32 => OneBasedU64, OneBasedU128);
 impl_from_one_based!(OneBasedU64 => OneBasedU128);
 
+macro_rules! impl_from_one_based_to_usize {
+    ($($source:ty),+) => {$(
+        impl core::convert::From<$source> for OneBasedUsize {
+            #[doc = concat!(r"Converts [`", stringify!($source), r"`] to [`OneBasedUsize`].")]
+            #[inline]
+            fn from(value: $source) -> Self {
+                // this is lossless on the target, and cannot be zero.
+                unsafe { OneBasedUsize::from_one_based_unchecked(value.as_one_based().get() as usize) }
+            }
+        }
+    )*};
+}
+
+impl_from_one_based_to_usize!(OneBasedU8, OneBasedU16);
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_from_one_based_to_usize!(OneBasedU32);
+#[cfg(target_pointer_width = "64")]
+impl_from_one_based_to_usize!(OneBasedU64);
+
 macro_rules! impl_try_from_one_based {
     ($source:ty => $($target:ty),+) => {$(
         impl core::convert::TryFrom<$source> for $target {
@@ -186,33 +1487,282 @@ macro_rules! impl_try_from_one_based {
             #[inline]
             fn try_from(value: $source) -> Result<Self, Self::Error> {
                 use core::convert::TryInto as _;
-                let v: <$target as OneBased>::NonZeroType = value.as_one_based().try_into()?;
+                let v: <$target as OneBasedInteger>::NonZero = value.as_one_based().try_into()?;
                 Ok(<$target>::from_one_based_nonzero(v))
             }
         }
     )*};
 }
 
-impl_try_from_one_based!(OneBasedU8 => OneBasedUsize);
-impl_try_from_one_based!(OneBasedU16 => OneBasedUsize, OneBasedU8);
-impl_try_from_one_based!(OneBasedU32 => OneBasedUsize, OneBasedU8, OneBasedU16);
-impl_try_from_one_based!(OneBasedU64 => OneBasedUsize, OneBasedU8, OneBasedU16, OneBasedU32);
+impl_try_from_one_based!(OneBasedU16 => OneBasedU8);
+impl_try_from_one_based!(OneBasedU32 => OneBasedU8, OneBasedU16);
+impl_try_from_one_based!(OneBasedU64 => OneBasedU8, OneBasedU16, OneBasedU32);
+#[cfg(not(any(target_pointer_width = "32", target_pointer_width = "64")))]
+impl_try_from_one_based!(OneBasedU32 => OneBasedUsize);
+#[cfg(not(target_pointer_width = "64"))]
+impl_try_from_one_based!(OneBasedU64 => OneBasedUsize);
 impl_try_from_one_based!(OneBasedU128 => OneBasedUsize, OneBasedU8, OneBasedU16, OneBasedU32, OneBasedU64);
 impl_try_from_one_based!(OneBasedUsize => OneBasedU8, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU128);
 
+macro_rules! impl_saturating_into {
+    ($source:ty => $($method:ident: $target:ident, $titype:ty);+) => {
+        impl $source {$(
+            #[doc = concat!(r" Converts to [`", stringify!($target), r"`], clamping to its MAX value if it doesn't fit.")]
+            #[inline]
+            pub const fn $method(self) -> $target {
+                // all unsigned types fit in u128.
+                if self.0.get() as u128 > <$titype>::MAX as u128 {
+                    return $target::MAX;
+                }
+                // this won't overflow, and cannot be zero.
+                unsafe { $target::from_one_based_unchecked(self.0.get() as $titype) }
+            }
+        )*}
+    };
+}
+
+impl_saturating_into!(OneBasedU8 => saturating_into_usize: OneBasedUsize, usize);
+impl_saturating_into!(OneBasedU16 =>
+    saturating_into_usize: OneBasedUsize, usize;
+    saturating_into_u8: OneBasedU8, u8);
+impl_saturating_into!(OneBasedU32 =>
+    saturating_into_usize: OneBasedUsize, usize;
+    saturating_into_u8: OneBasedU8, u8;
+    saturating_into_u16: OneBasedU16, u16);
+impl_saturating_into!(OneBasedU64 =>
+    saturating_into_usize: OneBasedUsize, usize;
+    saturating_into_u8: OneBasedU8, u8;
+    saturating_into_u16: OneBasedU16, u16;
+    saturating_into_u32: OneBasedU32, u32);
+impl_saturating_into!(OneBasedU128 =>
+    saturating_into_usize: OneBasedUsize, usize;
+    saturating_into_u8: OneBasedU8, u8;
+    saturating_into_u16: OneBasedU16, u16;
+    saturating_into_u32: OneBasedU32, u32;
+    saturating_into_u64: OneBasedU64, u64);
+impl_saturating_into!(OneBasedUsize =>
+    saturating_into_u8: OneBasedU8, u8;
+    saturating_into_u16: OneBasedU16, u16;
+    saturating_into_u32: OneBasedU32, u32;
+    saturating_into_u64: OneBasedU64, u64;
+    saturating_into_u128: OneBasedU128, u128);
+
+macro_rules! impl_try_from_signed {
+    ($source:ty => $($target:ty),+) => {$(
+        impl core::convert::TryFrom<$source> for $target {
+            type Error = OneBasedError;
+
+            #[doc = concat!(r"Attempts to convert 1-based [`", stringify!($source), r"`] to [`", stringify!($target), r"`].")]
+            #[inline]
+            fn try_from(value: $source) -> Result<Self, Self::Error> {
+                type Int = <$target as OneBasedInteger>::Int;
+                if value < 0 {
+                    return Err(OneBasedError::NegativeIndex { value: value as i128 });
+                }
+                // non-negative $source always fits in u128.
+                if value as u128 > Int::MAX as u128 {
+                    return Err(OneBasedError::OutOfRangeIndex {
+                        value: value as u128,
+                        bits: Int::BITS,
+                    });
+                }
+                <$target>::from_one_based(value as Int)
+            }
+        }
+    )*};
+}
+
+impl_try_from_signed!(i8 => OneBasedU8, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU128, OneBasedUsize);
+impl_try_from_signed!(i16 => OneBasedU8, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU128, OneBasedUsize);
+impl_try_from_signed!(i32 => OneBasedU8, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU128, OneBasedUsize);
+impl_try_from_signed!(i64 => OneBasedU8, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU128, OneBasedUsize);
+impl_try_from_signed!(i128 => OneBasedU8, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU128, OneBasedUsize);
+impl_try_from_signed!(isize => OneBasedU8, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU128, OneBasedUsize);
+
+macro_rules! impl_from_one_based_to_signed {
+    ($source:ty => $($target:ty),+) => {$(
+        impl core::convert::From<$source> for $target {
+            #[doc = concat!(r"Converts [`", stringify!($source), r"`] to 1-based [`", stringify!($target), r"`].")]
+            #[inline]
+            fn from(value: $source) -> Self {
+                <$target>::from(value.as_one_based().get())
+            }
+        }
+    )*};
+}
+
+impl_from_one_based_to_signed!(OneBasedU8 => i16, i32, i64, i128, isize);
+impl_from_one_based_to_signed!(OneBasedU16 => i32, i64, i128);
+impl_from_one_based_to_signed!(OneBasedU32 => i64, i128);
+impl_from_one_based_to_signed!(OneBasedU64 => i128);
+
+/// Implements [`valuable::Valuable`], so that structured logs such as `tracing` record
+/// the 1-based value as number instead of the `Debug` string.
+/// Without `valuable` support, record the primitive value returned by `get()` instead.
+#[cfg(feature = "valuable")]
+macro_rules! impl_valuable {
+    ($($name:ident => $variant:ident),+) => {$(
+        impl valuable::Valuable for $name {
+            #[doc = concat!(r"Returns the 1-based value as [`valuable::Value::", stringify!($variant), r"`].")]
+            fn as_value(&self) -> valuable::Value<'_> {
+                valuable::Value::$variant(self.get())
+            }
+
+            fn visit(&self, visit: &mut dyn valuable::Visit) {
+                visit.visit_value(self.as_value())
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "valuable")]
+impl_valuable!(
+    OneBasedU8 => U8,
+    OneBasedU16 => U16,
+    OneBasedU32 => U32,
+    OneBasedU64 => U64,
+    OneBasedU128 => U128,
+    OneBasedUsize => Usize
+);
+
+/// Implements [`validator::ValidateRange`], so that `#[validate(range(min = 1, max = 100))]`
+/// is evaluated against the 1-based value.
+/// This also requires `serde` feature, as the derived validation records the offending value.
+#[cfg(feature = "validator")]
+macro_rules! impl_validate_range {
+    ($($name:ident => $itype:ty),+) => {$(
+        impl validator::ValidateRange<$itype> for $name {
+            fn greater_than(&self, max: $itype) -> Option<bool> {
+                Some(*self > max)
+            }
+
+            fn less_than(&self, min: $itype) -> Option<bool> {
+                Some(*self < min)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "validator")]
+impl_validate_range!(
+    OneBasedU8 => u8,
+    OneBasedU16 => u16,
+    OneBasedU32 => u32,
+    OneBasedU64 => u64,
+    OneBasedU128 => u128,
+    OneBasedUsize => usize
+);
+
+/// Implements [`garde::rules::range::Bounds`], so that `#[garde(range(min = 1, max = 100))]`
+/// is evaluated against the 1-based value.
+#[cfg(feature = "garde")]
+macro_rules! impl_garde_bounds {
+    ($($name:ident => $itype:ty),+) => {$(
+        impl garde::rules::range::Bounds for $name {
+            type Size = $itype;
+
+            const MIN: $itype = 1;
+            const MAX: $itype = <$itype>::MAX;
+
+            fn validate_bounds(
+                &self,
+                lower_bound: $itype,
+                upper_bound: $itype,
+            ) -> Result<(), garde::rules::range::OutOfBounds> {
+                let v = self.as_one_based().get();
+                if v < lower_bound {
+                    Err(garde::rules::range::OutOfBounds::Lower)
+                } else if v > upper_bound {
+                    Err(garde::rules::range::OutOfBounds::Upper)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "garde")]
+impl_garde_bounds!(
+    OneBasedU8 => u8,
+    OneBasedU16 => u16,
+    OneBasedU32 => u32,
+    OneBasedU64 => u64,
+    OneBasedU128 => u128,
+    OneBasedUsize => usize
+);
+
+/// Error message used by deserializers when 0 is given.
+#[cfg(any(
+    feature = "async-graphql",
+    feature = "borsh",
+    feature = "juniper",
+    feature = "minicbor",
+    feature = "musli",
+    feature = "redis",
+    feature = "serde"
+))]
+const ZERO_DESERIALIZE_MESSAGE: &str = "invalid value 0: 1-based index must be >= 1";
+
 /// Error type used when converting integer to OneBased* types.
+///
+/// More variants may be added in the future, so that the error can carry more context.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum OneBasedError {
+    /// 0 is passed as 1-based index.
     ZeroIndex,
-    OverflowIndex,
+    /// The max value is passed as 0-based index, which has no 1-based counterpart.
+    OverflowIndex {
+        /// The passed 0-based value.
+        value: u128,
+        /// The bit width of the target type.
+        bits: u32,
+    },
+    /// The negative value is passed as index.
+    NegativeIndex {
+        /// The passed value.
+        value: i128,
+    },
+    /// The 1-based value is too large for the target type.
+    OutOfRangeIndex {
+        /// The passed value.
+        value: u128,
+        /// The bit width of the target type.
+        bits: u32,
+    },
+    /// The 1-based index is out of bounds of the collection.
+    OutOfBounds {
+        /// The passed 1-based index.
+        index: usize,
+        /// The length of the collection.
+        len: usize,
+    },
 }
 
 impl Display for OneBasedError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             OneBasedError::ZeroIndex => f.write_str("0 passed as 1-based index"),
-            OneBasedError::OverflowIndex => {
-                f.write_str("unsigned::MAX cannot be used as 0-based index")
+            OneBasedError::OverflowIndex { value, bits } => {
+                write!(
+                    f,
+                    "0-based index {} has no 1-based counterpart in {}-bit type",
+                    value, bits
+                )
+            }
+            OneBasedError::NegativeIndex { value } => {
+                write!(f, "negative value {} passed as index", value)
+            }
+            OneBasedError::OutOfRangeIndex { value, bits } => {
+                write!(f, "index {} is too large for {}-bit type", value, bits)
+            }
+            OneBasedError::OutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "1-based index {} is out of bounds for length {}",
+                    index, len
+                )
             }
         }
     }
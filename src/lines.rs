@@ -0,0 +1,102 @@
+//! Provides line iterators of [`BufRead`] numbered with `OneBasedUsize`.
+
+use std::io::{self, BufRead};
+
+use crate::{CheckedIterFrom, OneBasedUsize};
+
+/// Returns the iterator over the lines of the reader, numbered from 1.
+///
+/// Same as [`BufRead::lines`], each line doesn't contain the newline `\n` or `\r\n`.
+/// Once the line number overflows, it yields an error and stops.
+///
+/// ```
+/// # use one_based::lines_numbered;
+/// let text = "first\nsecond\r\n";
+/// let lines: Vec<(usize, String)> = lines_numbered(text.as_bytes())
+///     .map(|line| line.map(|(i, line)| (i.get(), line)))
+///     .collect::<Result<_, _>>()?;
+/// assert_eq!(lines, [(1, "first".to_string()), (2, "second".to_string())]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn lines_numbered<R: BufRead>(reader: R) -> LinesNumbered<R> {
+    LinesNumbered {
+        lines: reader.lines(),
+        numbers: OneBasedUsize::FIRST.checked_iter_from(),
+    }
+}
+
+/// Returns the iterator over the lines of the reader as bytes, numbered from 1.
+///
+/// This is same as [`lines_numbered`], except that the lines don't have to be valid UTF-8.
+///
+/// ```
+/// # use one_based::byte_lines_numbered;
+/// let text = b"first\n\xFF\r\n";
+/// let lines: Vec<(usize, Vec<u8>)> = byte_lines_numbered(&text[..])
+///     .map(|line| line.map(|(i, line)| (i.get(), line)))
+///     .collect::<Result<_, _>>()?;
+/// assert_eq!(lines, [(1, b"first".to_vec()), (2, b"\xFF".to_vec())]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn byte_lines_numbered<R: BufRead>(reader: R) -> ByteLinesNumbered<R> {
+    ByteLinesNumbered {
+        lines: reader.split(b'\n'),
+        numbers: OneBasedUsize::FIRST.checked_iter_from(),
+    }
+}
+
+/// Numbers the line read, or returns the error.
+fn number<T>(
+    numbers: &mut CheckedIterFrom<OneBasedUsize>,
+    line: io::Result<T>,
+) -> Option<io::Result<(OneBasedUsize, T)>> {
+    let line = match line {
+        Ok(line) => line,
+        Err(e) => return Some(Err(e)),
+    };
+    Some(match numbers.next()? {
+        Ok(i) => Ok((i, line)),
+        Err(e) => Err(io::Error::other(e)),
+    })
+}
+
+/// Iterator over the numbered lines of [`BufRead`].
+///
+/// This is created by [`lines_numbered`].
+#[derive(Debug)]
+pub struct LinesNumbered<R> {
+    lines: io::Lines<R>,
+    numbers: CheckedIterFrom<OneBasedUsize>,
+}
+
+impl<R: BufRead> Iterator for LinesNumbered<R> {
+    type Item = io::Result<(OneBasedUsize, String)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        number(&mut self.numbers, line)
+    }
+}
+
+/// Iterator over the numbered lines of [`BufRead`] as bytes.
+///
+/// This is created by [`byte_lines_numbered`].
+#[derive(Debug)]
+pub struct ByteLinesNumbered<R> {
+    lines: io::Split<R>,
+    numbers: CheckedIterFrom<OneBasedUsize>,
+}
+
+impl<R: BufRead> Iterator for ByteLinesNumbered<R> {
+    type Item = io::Result<(OneBasedUsize, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?.map(|mut line| {
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            line
+        });
+        number(&mut self.numbers, line)
+    }
+}
@@ -0,0 +1,92 @@
+//! Provides parser of editor "goto" locators such as "12:34".
+
+use core::{fmt::Display, num::ParseIntError, str::FromStr};
+
+use crate::{parse, OneBasedUsize};
+
+/// Error type used when parsing [`Locator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseLocatorError {
+    /// The line number is invalid.
+    InvalidLine(ParseIntError),
+    /// The column number is invalid.
+    InvalidColumn(ParseIntError),
+}
+
+impl Display for ParseLocatorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseLocatorError::InvalidLine(e) => write!(f, "invalid line number: {}", e),
+            ParseLocatorError::InvalidColumn(e) => write!(f, "invalid column number: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseLocatorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseLocatorError::InvalidLine(e) | ParseLocatorError::InvalidColumn(e) => Some(e),
+        }
+    }
+}
+
+/// Position in the text given by editor "goto" syntax,
+/// which consists of 1-based line and optional 1-based column.
+///
+/// Accepts "12", ":12", "+12" and "12:34" forms,
+/// where the prefix `:` or `+` is allowed for each of them.
+///
+/// ```
+/// # use one_based::Locator;
+/// let loc: Locator = "+12:34".parse()?;
+/// assert_eq!(loc.line.as_zero_based(), 11);
+/// assert_eq!(loc.column.map(|c| c.as_zero_based()), Some(33));
+/// assert_eq!(loc.to_string(), "12:34");
+/// # Ok::<(), one_based::ParseLocatorError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Locator {
+    /// 1-based line number.
+    pub line: OneBasedUsize,
+    /// 1-based column number, if given.
+    pub column: Option<OneBasedUsize>,
+}
+
+/// Parses the number only consisting of ASCII digits.
+fn parse_digits(s: &str) -> Result<OneBasedUsize, ParseIntError> {
+    if !s.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(parse::invalid_digit_error());
+    }
+    s.parse()
+}
+
+impl FromStr for Locator {
+    type Err = ParseLocatorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s
+            .strip_prefix(':')
+            .or_else(|| s.strip_prefix('+'))
+            .unwrap_or(s);
+        let (line, column) = match s.split_once(':') {
+            None => (s, None),
+            Some((line, column)) => (line, Some(column)),
+        };
+        let line = parse_digits(line).map_err(ParseLocatorError::InvalidLine)?;
+        let column = column
+            .map(parse_digits)
+            .transpose()
+            .map_err(ParseLocatorError::InvalidColumn)?;
+        Ok(Locator { line, column })
+    }
+}
+
+impl Display for Locator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.column {
+            None => write!(f, "{}", self.line),
+            Some(column) => write!(f, "{}:{}", self.line, column),
+        }
+    }
+}
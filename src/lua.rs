@@ -0,0 +1,66 @@
+//! Provides [`mlua`] integration.
+//!
+//! As Lua is natively 1-based, `OneBased*` types are converted to Lua integer
+//! holding the 1-based value as is, and [`OneBasedUsize`] is the natural type
+//! to pass table or array indices between Rust and Lua.
+//! Converting zero, negative, too large or non-integral value from Lua fails.
+//!
+//! Note that this crate doesn't choose the Lua version,
+//! so enable one of `mlua` features such as `lua54` in your crate.
+//!
+//! ```
+//! # use one_based::OneBasedUsize;
+//! use mlua::Lua;
+//!
+//! let lua = Lua::new();
+//! let items = ["apple", "banana", "cherry"];
+//! let get = lua.create_function(move |_, i: OneBasedUsize| Ok(items.get(i.as_zero_based()).copied()))?;
+//! lua.globals().set("get", get)?;
+//!
+//! assert_eq!(lua.load("return get(2)").eval::<String>()?, "banana");
+//! assert!(lua.load("return get(0)").eval::<String>().is_err());
+//! # Ok::<(), mlua::Error>(())
+//! ```
+
+use ::mlua::{Error, FromLua, IntoLua, Lua, Result, Value};
+
+use crate::{OneBasedU128, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize};
+
+macro_rules! impl_mlua {
+    ($($name:ident, $itype:ty);+) => {$(
+        impl<'lua> IntoLua<'lua> for $name {
+            #[doc = concat!(r"Converts [`", stringify!($name), r"`] to 1-based Lua integer.")]
+            fn into_lua(self, lua: &'lua Lua) -> Result<Value<'lua>> {
+                self.as_one_based().get().into_lua(lua)
+            }
+        }
+
+        impl<'lua> FromLua<'lua> for $name {
+            fn from_lua(value: Value<'lua>, lua: &'lua Lua) -> Result<Self> {
+                let from = value.type_name();
+                let error = |message: String| Error::FromLuaConversionError {
+                    from,
+                    to: stringify!($name),
+                    message: Some(message),
+                };
+                // primitive integers silently truncate the fractional part.
+                if let Value::Number(n) = value {
+                    if n.fract() != 0.0 {
+                        return Err(error("number has no integer representation".to_owned()));
+                    }
+                }
+                let v = <$itype>::from_lua(value, lua)?;
+                <$name>::from_one_based(v).map_err(|e| error(e.to_string()))
+            }
+        }
+    )*};
+}
+
+impl_mlua!(
+    OneBasedU8, u8;
+    OneBasedU16, u16;
+    OneBasedU32, u32;
+    OneBasedU64, u64;
+    OneBasedU128, u128;
+    OneBasedUsize, usize
+);
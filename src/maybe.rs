@@ -0,0 +1,171 @@
+//! Provides MaybeOneBased* types, which use 0 as "none" sentinel.
+
+use crate::{OneBasedU128, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize};
+
+macro_rules! define_maybe_one_based {
+    ($name:ident, $onebased:ident, $itype:ty) => {
+        #[doc = concat!(r" Represents optional 1-based index of ", stringify!($itype), r", where 0 means none.")]
+        ///
+        #[doc = concat!(r" This type has the same layout as plain `", stringify!($itype), r"`,")]
+        /// which is common convention in many wire formats and C APIs.
+        #[doc = concat!(r" Use [`", stringify!($name), r"::get()`] to safely obtain [`", stringify!($onebased), r"`].")]
+        ///
+        /// ```
+        #[doc = concat!(r" # use one_based::{", stringify!($name), r", ", stringify!($onebased), r"};")]
+        #[doc = concat!(r" let v = ", stringify!($name), r"::from_raw(5);")]
+        #[doc = concat!(r" assert_eq!(v.get(), Some(", stringify!($onebased), r"::from_one_based(5)?));")]
+        #[doc = r""]
+        #[doc = concat!(r" let v = ", stringify!($name), r"::from_raw(0);")]
+        #[doc = r" assert_eq!(v.get(), None);"]
+        #[doc = r" # Ok::<(), one_based::OneBasedError>(())"]
+        /// ```
+        #[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+        #[repr(transparent)]
+        pub struct $name($itype);
+
+        impl core::convert::From<Option<$onebased>> for $name {
+            #[doc = concat!(r"Converts `Option<", stringify!($onebased), r">` to [`", stringify!($name), r"`].")]
+            #[inline]
+            fn from(value: Option<$onebased>) -> Self {
+                match value {
+                    None => Self::NONE,
+                    Some(v) => Self::some(v),
+                }
+            }
+        }
+
+        impl core::convert::From<$onebased> for $name {
+            #[doc = concat!(r"Converts [`", stringify!($onebased), r"`] to [`", stringify!($name), r"`].")]
+            #[inline]
+            fn from(value: $onebased) -> Self {
+                Self::some(value)
+            }
+        }
+
+        impl core::convert::From<$name> for Option<$onebased> {
+            #[doc = concat!(r"Converts [`", stringify!($name), r"`] to `Option<", stringify!($onebased), r">`.")]
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            /// Serializes the raw value as the bare unsigned integer, where 0 means none.
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            /// Deserializes the raw value from the bare unsigned integer, where 0 means none.
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                serde::Deserialize::deserialize(deserializer).map(Self)
+            }
+        }
+
+        #[cfg(feature = "bincode")]
+        impl bincode::Encode for $name {
+            /// Encodes the raw value, where 0 means none.
+            fn encode<E: bincode::enc::Encoder>(
+                &self,
+                encoder: &mut E,
+            ) -> Result<(), bincode::error::EncodeError> {
+                bincode::Encode::encode(&self.0, encoder)
+            }
+        }
+
+        #[cfg(feature = "bincode")]
+        impl<Context> bincode::Decode<Context> for $name {
+            /// Decodes the raw value, where 0 means none.
+            fn decode<D: bincode::de::Decoder<Context = Context>>(
+                decoder: &mut D,
+            ) -> Result<Self, bincode::error::DecodeError> {
+                bincode::Decode::decode(decoder).map(Self)
+            }
+        }
+
+        #[cfg(feature = "bincode")]
+        bincode::impl_borrow_decode!($name);
+
+        #[cfg(feature = "borsh")]
+        impl borsh::BorshSerialize for $name {
+            /// Serializes the raw value, where 0 means none.
+            fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+                borsh::BorshSerialize::serialize(&self.0, writer)
+            }
+        }
+
+        #[cfg(feature = "borsh")]
+        impl borsh::BorshDeserialize for $name {
+            /// Deserializes the raw value, where 0 means none.
+            fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+                borsh::BorshDeserialize::deserialize_reader(reader).map(Self)
+            }
+        }
+
+        #[cfg(feature = "postcard")]
+        impl postcard::experimental::max_size::MaxSize for $name {
+            const POSTCARD_MAX_SIZE: usize =
+                <$itype as postcard::experimental::max_size::MaxSize>::POSTCARD_MAX_SIZE;
+        }
+
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for $name {
+            /// Emits the 1-based value if present.
+            fn format(&self, f: defmt::Formatter<'_>) {
+                self.get().format(f)
+            }
+        }
+
+        impl $name {
+            /// Represents absence of the index, which is encoded as 0.
+            pub const NONE: Self = Self(0);
+
+            /// Creates `$name` from the raw value, where 0 means none.
+            #[inline]
+            pub const fn from_raw(v: $itype) -> Self {
+                Self(v)
+            }
+
+            #[doc = concat!(r" Creates `$name` holding the given [`", stringify!($onebased), r"`].")]
+            #[inline]
+            pub const fn some(v: $onebased) -> Self {
+                Self(v.as_one_based().get())
+            }
+
+            /// Returns the raw value, where 0 means none.
+            #[inline]
+            pub const fn as_raw(&self) -> $itype {
+                self.0
+            }
+
+            #[doc = concat!(r" Returns [`", stringify!($onebased), r"`] if present.")]
+            #[inline]
+            pub const fn get(&self) -> Option<$onebased> {
+                $onebased::from_one_based_opt(self.0)
+            }
+
+            /// Returns `true` if the index is present.
+            #[inline]
+            pub const fn is_some(&self) -> bool {
+                self.0 != 0
+            }
+
+            /// Returns `true` if the index is absent.
+            #[inline]
+            pub const fn is_none(&self) -> bool {
+                self.0 == 0
+            }
+        }
+    };
+}
+
+define_maybe_one_based!(MaybeOneBasedU8, OneBasedU8, u8);
+define_maybe_one_based!(MaybeOneBasedU16, OneBasedU16, u16);
+define_maybe_one_based!(MaybeOneBasedU32, OneBasedU32, u32);
+define_maybe_one_based!(MaybeOneBasedU64, OneBasedU64, u64);
+define_maybe_one_based!(MaybeOneBasedU128, OneBasedU128, u128);
+define_maybe_one_based!(MaybeOneBasedUsize, OneBasedUsize, usize);
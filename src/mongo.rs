@@ -0,0 +1,141 @@
+//! Provides [`bson`] integration.
+//!
+//! `OneBased*` types are converted to BSON integers holding the 1-based value,
+//! and converted back from `Int32`, `Int64` or integral `Double`.
+//!
+//! ```
+//! # use one_based::OneBasedU32;
+//! use bson::{doc, Bson};
+//! use std::convert::TryFrom;
+//!
+//! let position = OneBasedU32::from_one_based(3).unwrap();
+//! let d = doc! { "position": position };
+//! assert_eq!(d.get("position"), Some(&Bson::Int32(3)));
+//!
+//! let v = OneBasedU32::try_from(d.get("position").unwrap()).unwrap();
+//! assert_eq!(v, position);
+//! assert!(OneBasedU32::try_from(Bson::Int64(0)).is_err());
+//! ```
+
+use core::{
+    convert::{TryFrom, TryInto},
+    fmt::Display,
+    num::TryFromIntError,
+};
+
+use ::bson::Bson;
+
+use crate::{
+    OneBasedError, OneBasedU128, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize,
+};
+
+/// Error type used when converting [`Bson`] into `OneBased*` types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromBsonError {
+    /// The value is not an integer, nor an integral `Double`.
+    NotInteger,
+    /// The integer is not a valid 1-based index of the type.
+    InvalidIndex(OneBasedError),
+}
+
+impl Display for FromBsonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FromBsonError::NotInteger => f.write_str("BSON value is not an integer"),
+            FromBsonError::InvalidIndex(e) => write!(f, "invalid BSON index: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for FromBsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FromBsonError::NotInteger => None,
+            FromBsonError::InvalidIndex(e) => Some(e),
+        }
+    }
+}
+
+impl From<OneBasedError> for FromBsonError {
+    fn from(e: OneBasedError) -> Self {
+        FromBsonError::InvalidIndex(e)
+    }
+}
+
+/// Returns the integer value of `Int32`, `Int64` or integral `Double`.
+fn as_i64(v: &Bson) -> Result<i64, FromBsonError> {
+    match *v {
+        Bson::Int32(v) => Ok(v.into()),
+        Bson::Int64(v) => Ok(v),
+        Bson::Double(v) if v.is_finite() && v.fract() == 0.0 => {
+            if v < 0.0 {
+                Err(OneBasedError::NegativeIndex { value: v as i128 }.into())
+            } else if v >= i64::MAX as f64 {
+                Err(OneBasedError::OutOfRangeIndex {
+                    value: v as u128,
+                    bits: i64::BITS,
+                }
+                .into())
+            } else {
+                Ok(v as i64)
+            }
+        }
+        _ => Err(FromBsonError::NotInteger),
+    }
+}
+
+macro_rules! impl_try_from_bson {
+    ($($name:ident),+) => {$(
+        impl TryFrom<&Bson> for $name {
+            type Error = FromBsonError;
+
+            #[doc = concat!(r"Attempts to convert 1-based BSON integer to [`", stringify!($name), r"`].")]
+            fn try_from(value: &Bson) -> Result<Self, Self::Error> {
+                Ok(<$name>::try_from_db_i64(as_i64(value)?)?)
+            }
+        }
+
+        impl TryFrom<Bson> for $name {
+            type Error = FromBsonError;
+
+            #[doc = concat!(r"Attempts to convert 1-based BSON integer to [`", stringify!($name), r"`].")]
+            fn try_from(value: Bson) -> Result<Self, Self::Error> {
+                <$name>::try_from(&value)
+            }
+        }
+    )*};
+}
+
+impl_try_from_bson!(
+    OneBasedU8,
+    OneBasedU16,
+    OneBasedU32,
+    OneBasedU64,
+    OneBasedU128,
+    OneBasedUsize
+);
+
+macro_rules! impl_into_bson {
+    (lossless: $($name:ident),+) => {$(
+        impl From<$name> for Bson {
+            #[doc = concat!(r"Converts [`", stringify!($name), r"`] to 1-based BSON integer, using `Int32` if it fits.")]
+            fn from(value: $name) -> Self {
+                Bson::from(u32::from(value.as_one_based().get()))
+            }
+        }
+    )*};
+    (fallible: $($name:ident),+) => {$(
+        impl TryFrom<$name> for Bson {
+            type Error = TryFromIntError;
+
+            #[doc = concat!(r"Attempts to convert [`", stringify!($name), r"`] to 1-based BSON `Int64`.")]
+            fn try_from(value: $name) -> Result<Self, Self::Error> {
+                let v: i64 = value.as_one_based().get().try_into()?;
+                Ok(Bson::Int64(v))
+            }
+        }
+    )*};
+}
+
+impl_into_bson!(lossless: OneBasedU8, OneBasedU16, OneBasedU32);
+impl_into_bson!(fallible: OneBasedU64, OneBasedU128, OneBasedUsize);
@@ -0,0 +1,140 @@
+//! Provides [`num_traits`] integration.
+//!
+//! Arithmetic operators required by [`One`], [`CheckedAdd`] and [`CheckedSub`]
+//! operate on the 1-based values, and panic if the result overflows or becomes zero.
+
+use core::ops::{Add, Mul, Sub};
+
+use ::num_traits::{Bounded, CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, One, ToPrimitive};
+
+use crate::{OneBasedU128, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize};
+
+macro_rules! impl_num_traits {
+    ($($name:ident, $itype:ty);+) => {$(
+        impl Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                CheckedAdd::checked_add(&self, &rhs).expect("attempt to add with overflow")
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                CheckedSub::checked_sub(&self, &rhs)
+                    .expect("attempt to subtract with overflow or into zero")
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                CheckedMul::checked_mul(&self, &rhs).expect("attempt to multiply with overflow")
+            }
+        }
+
+        impl CheckedAdd for $name {
+            #[inline]
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                self.as_one_based()
+                    .checked_add(v.as_one_based().get())
+                    .map(Self::from_one_based_nonzero)
+            }
+        }
+
+        impl CheckedSub for $name {
+            #[inline]
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                let v = self.as_one_based().get().checked_sub(v.as_one_based().get())?;
+                Self::from_one_based_opt(v)
+            }
+        }
+
+        impl CheckedMul for $name {
+            #[inline]
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                self.as_one_based()
+                    .checked_mul(v.as_one_based())
+                    .map(Self::from_one_based_nonzero)
+            }
+        }
+
+        impl Bounded for $name {
+            #[inline]
+            fn min_value() -> Self {
+                Self::FIRST
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl One for $name {
+            #[inline]
+            fn one() -> Self {
+                Self::FIRST
+            }
+        }
+
+        impl ToPrimitive for $name {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                self.as_one_based().get().to_i64()
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                self.as_one_based().get().to_u64()
+            }
+
+            #[inline]
+            fn to_i128(&self) -> Option<i128> {
+                self.as_one_based().get().to_i128()
+            }
+
+            #[inline]
+            fn to_u128(&self) -> Option<u128> {
+                self.as_one_based().get().to_u128()
+            }
+        }
+
+        impl FromPrimitive for $name {
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                Self::from_one_based_opt(<$itype>::from_i64(n)?)
+            }
+
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                Self::from_one_based_opt(<$itype>::from_u64(n)?)
+            }
+
+            #[inline]
+            fn from_i128(n: i128) -> Option<Self> {
+                Self::from_one_based_opt(<$itype>::from_i128(n)?)
+            }
+
+            #[inline]
+            fn from_u128(n: u128) -> Option<Self> {
+                Self::from_one_based_opt(<$itype>::from_u128(n)?)
+            }
+        }
+    )*};
+}
+
+impl_num_traits!(
+    OneBasedU8, u8;
+    OneBasedU16, u16;
+    OneBasedU32, u32;
+    OneBasedU64, u64;
+    OneBasedU128, u128;
+    OneBasedUsize, usize
+);
@@ -0,0 +1,84 @@
+//! Provides [`utoipa`] integration.
+//!
+//! `OneBased*` types are described as integer with `minimum` 1,
+//! so that they can be used in `ToSchema` and `IntoParams` derives.
+//!
+//! ```
+//! # use one_based::OneBasedU32;
+//! use utoipa::{IntoParams, PartialSchema};
+//!
+//! #[derive(utoipa::ToSchema)]
+//! struct Cursor {
+//!     line: OneBasedU32,
+//! }
+//!
+//! #[derive(IntoParams)]
+//! #[into_params(parameter_in = Query)]
+//! struct Pagination {
+//!     #[param(inline)]
+//!     page: OneBasedU32,
+//! }
+//!
+//! let schema = serde_json::to_value(Cursor::schema()).unwrap();
+//! assert_eq!(schema["properties"]["line"]["$ref"], "#/components/schemas/OneBasedU32");
+//!
+//! let params = serde_json::to_value(Pagination::into_params(|| None)).unwrap();
+//! assert_eq!(params[0]["schema"]["minimum"], 1);
+//! ```
+
+use alloc::borrow::Cow;
+
+use ::utoipa::{
+    openapi::{
+        schema::{KnownFormat, ObjectBuilder, SchemaFormat, Type},
+        RefOr, Schema,
+    },
+    PartialSchema, ToSchema,
+};
+
+use crate::{
+    OneBasedStoredZeroU128, OneBasedStoredZeroU16, OneBasedStoredZeroU32, OneBasedStoredZeroU64,
+    OneBasedStoredZeroU8, OneBasedStoredZeroUsize, OneBasedU128, OneBasedU16, OneBasedU32,
+    OneBasedU64, OneBasedU8, OneBasedUsize,
+};
+
+macro_rules! impl_to_schema {
+    ($($name:ident, $stored_zero:ident, $format:ident);+) => {$(
+        impl PartialSchema for $name {
+            fn schema() -> RefOr<Schema> {
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::$format)))
+                    .minimum(Some(1))
+                    .into()
+            }
+        }
+
+        impl ToSchema for $name {
+            fn name() -> Cow<'static, str> {
+                Cow::Borrowed(stringify!($name))
+            }
+        }
+
+        impl PartialSchema for $stored_zero {
+            fn schema() -> RefOr<Schema> {
+                <$name>::schema()
+            }
+        }
+
+        impl ToSchema for $stored_zero {
+            fn name() -> Cow<'static, str> {
+                Cow::Borrowed(stringify!($stored_zero))
+            }
+        }
+    )*};
+}
+
+impl_to_schema!(
+    OneBasedU8, OneBasedStoredZeroU8, Int32;
+    OneBasedU16, OneBasedStoredZeroU16, Int32;
+    OneBasedU32, OneBasedStoredZeroU32, Int32;
+    OneBasedU64, OneBasedStoredZeroU64, Int64;
+    OneBasedU128, OneBasedStoredZeroU128, Int64;
+    OneBasedUsize, OneBasedStoredZeroUsize, Int64
+);
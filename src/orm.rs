@@ -0,0 +1,99 @@
+//! Provides [`diesel`] integration.
+//!
+//! `OneBasedU32` is mapped to `Integer`, and `OneBasedU64` is mapped to `BigInt`,
+//! both holding the 1-based value.
+//! Serialization fails if the value doesn't fit in the signed SQL type,
+//! and deserialization fails on zero, negative or too large values.
+//!
+//! Serialization is implemented per backend, with `diesel-mysql`, `diesel-postgres`
+//! and `diesel-sqlite` features respectively.
+//!
+//! ```
+//! # #[cfg(feature = "diesel-sqlite")] {
+//! # use one_based::OneBasedU64;
+//! use diesel::{dsl::sql, prelude::*, sql_types::BigInt};
+//!
+//! let mut conn = SqliteConnection::establish(":memory:").unwrap();
+//! let v = OneBasedU64::from_one_based(5).unwrap();
+//! let got: OneBasedU64 = diesel::select(v.into_sql::<BigInt>())
+//!     .get_result(&mut conn)
+//!     .unwrap();
+//! assert_eq!(got, v);
+//!
+//! let zero = diesel::select(sql::<BigInt>("0")).get_result::<OneBasedU64>(&mut conn);
+//! assert!(zero.is_err());
+//! # }
+//! ```
+
+use core::convert::TryFrom;
+
+use ::diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    serialize::{self, Output, ToSql},
+    sql_types::{BigInt, Integer},
+};
+
+use crate::{OneBasedU32, OneBasedU64};
+
+macro_rules! impl_diesel {
+    ($($name:ident, $proxy:ident, $sql_type:ty, $stype:ty);+) => {$(
+        // Proxy type to derive the traits for the foreign type.
+        #[derive(AsExpression, FromSqlRow)]
+        #[diesel(foreign_derive)]
+        #[diesel(sql_type = $sql_type)]
+        #[allow(dead_code)]
+        struct $proxy($name);
+
+        impl<DB: Backend> FromSql<$sql_type, DB> for $name
+        where
+            $stype: FromSql<$sql_type, DB>,
+        {
+            fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                let v = <$stype as FromSql<$sql_type, DB>>::from_sql(bytes)?;
+                Ok(<$name>::try_from_db_i64(v.into())?)
+            }
+        }
+
+        impl $name {
+            /// Returns the value as the signed integer of the SQL type.
+            fn to_sql_int(self) -> Result<$stype, crate::OneBasedError> {
+                let v = self.as_one_based().get();
+                <$stype>::try_from(v).map_err(|_| crate::OneBasedError::OutOfRangeIndex {
+                    value: v as u128,
+                    bits: <$stype>::BITS,
+                })
+            }
+        }
+
+        #[cfg(feature = "diesel-mysql")]
+        impl ToSql<$sql_type, ::diesel::mysql::Mysql> for $name {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, ::diesel::mysql::Mysql>) -> serialize::Result {
+                let v = self.to_sql_int()?;
+                ToSql::<$sql_type, _>::to_sql(&v, &mut out.reborrow())
+            }
+        }
+
+        #[cfg(feature = "diesel-postgres")]
+        impl ToSql<$sql_type, ::diesel::pg::Pg> for $name {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, ::diesel::pg::Pg>) -> serialize::Result {
+                let v = self.to_sql_int()?;
+                ToSql::<$sql_type, _>::to_sql(&v, &mut out.reborrow())
+            }
+        }
+
+        #[cfg(feature = "diesel-sqlite")]
+        impl ToSql<$sql_type, ::diesel::sqlite::Sqlite> for $name {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, ::diesel::sqlite::Sqlite>) -> serialize::Result {
+                out.set_value(self.to_sql_int()?);
+                Ok(serialize::IsNull::No)
+            }
+        }
+    )*};
+}
+
+impl_diesel!(
+    OneBasedU32, OneBasedU32Proxy, Integer, i32;
+    OneBasedU64, OneBasedU64Proxy, BigInt, i64
+);
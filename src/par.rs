@@ -0,0 +1,44 @@
+//! Provides [`rayon`] integration.
+//!
+//! ```
+//! # use one_based::IndexedParallelIteratorExt;
+//! use rayon::prelude::*;
+//!
+//! let rows = ["1", "x", "3"];
+//! let errors: Vec<String> = rows
+//!     .par_iter()
+//!     .par_enumerate_one_based()
+//!     .filter(|(_, row)| row.parse::<u32>().is_err())
+//!     .map(|(i, row)| format!("row {}: invalid number {:?}", i, row))
+//!     .collect();
+//! assert_eq!(errors, [r#"row 2: invalid number "x""#]);
+//! ```
+
+use ::rayon::iter::{Enumerate, IndexedParallelIterator, Map, ParallelIterator};
+
+use crate::OneBasedUsize;
+
+/// Parallel iterator yielding the 1-based position along with the element.
+///
+/// This is created by [`IndexedParallelIteratorExt::par_enumerate_one_based`].
+pub type ParEnumerateOneBased<I> = Map<
+    Enumerate<I>,
+    fn((usize, <I as ParallelIterator>::Item)) -> (OneBasedUsize, <I as ParallelIterator>::Item),
+>;
+
+/// Extension trait of rayon indexed parallel iterators to deal with 1-based positions.
+pub trait IndexedParallelIteratorExt: IndexedParallelIterator {
+    /// Returns the parallel iterator yielding the 1-based position along with the element,
+    /// which is the 1-based version of [`IndexedParallelIterator::enumerate`].
+    #[inline]
+    fn par_enumerate_one_based(self) -> ParEnumerateOneBased<Self> {
+        self.enumerate().map(|(i, item)| {
+            // the 0-based position is always smaller than `usize::MAX`.
+            let i = OneBasedUsize::from_zero_based_opt(i)
+                .expect("too many elements for 1-based position");
+            (i, item)
+        })
+    }
+}
+
+impl<I: IndexedParallelIterator> IndexedParallelIteratorExt for I {}
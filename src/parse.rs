@@ -0,0 +1,330 @@
+//! Provides additional parsers for `OneBased*` types.
+
+use core::{
+    fmt::Display,
+    num::{IntErrorKind, ParseIntError},
+};
+
+use crate::{fmt::MAX_DIGITS, OneBased, UnsignedNonZero};
+
+/// Error type used when parsing ordinal string such as "3rd".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseOrdinalError {
+    /// The number part is invalid.
+    InvalidNumber(ParseIntError),
+    /// The suffix is missing, unknown, or doesn't match the number (e.g. "2st").
+    InvalidSuffix,
+}
+
+impl Display for ParseOrdinalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseOrdinalError::InvalidNumber(e) => write!(f, "invalid ordinal number: {}", e),
+            ParseOrdinalError::InvalidSuffix => f.write_str("invalid ordinal suffix"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseOrdinalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseOrdinalError::InvalidNumber(e) => Some(e),
+            ParseOrdinalError::InvalidSuffix => None,
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseOrdinalError {
+    fn from(value: ParseIntError) -> Self {
+        ParseOrdinalError::InvalidNumber(value)
+    }
+}
+
+/// Kind of [`ParseOneBasedError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseOneBasedErrorKind {
+    /// The input is empty.
+    Empty,
+    /// The input is not a number.
+    InvalidNumber,
+    /// The number is too large for the type.
+    OutOfRange,
+    /// The number is zero, which is not a valid 1-based index.
+    Zero,
+}
+
+/// Error type used when parsing `OneBased*` types from the user input.
+///
+/// Unlike [`ParseIntError`], this error explains why zero is rejected,
+/// and carries the original input when `std` feature is enabled,
+/// so that the message makes sense to end users.
+///
+/// [`OneBased::parse_index`] is the parser returning this error.
+/// The other parsers, such as [`FromStr`](core::str::FromStr), `from_str_radix`, `from_ascii`,
+/// [`OneBased::parse_lenient`] and [`OneBased::parse_strict`], keep returning [`ParseIntError`]
+/// same as the primitive integers, as [`OneBasedInteger`](crate::OneBasedInteger) requires it for `FromStr`.
+/// Their errors can be converted with [`ParseOneBasedError::new`].
+///
+/// ```
+/// # use one_based::{OneBasedU32, ParseOneBasedError, ParseOneBasedErrorKind};
+/// let err = OneBasedU32::parse_index("0").unwrap_err();
+/// assert_eq!(err.kind(), ParseOneBasedErrorKind::Zero);
+/// assert!(err.to_string().ends_with("indices start at 1"));
+///
+/// let input = "0x10";
+/// let err = OneBasedU32::parse_lenient(input).unwrap_err();
+/// assert_eq!(ParseOneBasedError::new(input, &err).kind(), ParseOneBasedErrorKind::InvalidNumber);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOneBasedError {
+    kind: ParseOneBasedErrorKind,
+    #[cfg(feature = "std")]
+    input: std::string::String,
+}
+
+impl ParseOneBasedError {
+    /// Creates `ParseOneBasedError` from [`ParseIntError`] returned for the given input,
+    /// such as the one returned by [`FromStr`](core::str::FromStr) of `OneBased*` types.
+    pub fn new(input: &str, err: &ParseIntError) -> Self {
+        let kind = match err.kind() {
+            IntErrorKind::Empty => ParseOneBasedErrorKind::Empty,
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                ParseOneBasedErrorKind::OutOfRange
+            }
+            IntErrorKind::Zero => ParseOneBasedErrorKind::Zero,
+            _ => ParseOneBasedErrorKind::InvalidNumber,
+        };
+        #[cfg(not(feature = "std"))]
+        let _ = input;
+        Self {
+            kind,
+            #[cfg(feature = "std")]
+            input: input.into(),
+        }
+    }
+
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> ParseOneBasedErrorKind {
+        self.kind
+    }
+
+    /// Returns the original input.
+    #[cfg(feature = "std")]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl Display for ParseOneBasedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid index")?;
+        #[cfg(feature = "std")]
+        write!(f, " {:?}", self.input)?;
+        f.write_str(match self.kind {
+            ParseOneBasedErrorKind::Empty => ": no number given",
+            ParseOneBasedErrorKind::InvalidNumber => ": not a number",
+            ParseOneBasedErrorKind::OutOfRange => ": number too large",
+            ParseOneBasedErrorKind::Zero => ": indices start at 1",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseOneBasedError {}
+
+/// Error type used when parsing [`OsStr`](std::ffi::OsStr), such as command line arguments.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseOneBasedOsError {
+    /// The input is not valid Unicode.
+    NotUnicode(std::ffi::OsString),
+    /// The input is not a valid 1-based number.
+    InvalidNumber(ParseIntError),
+}
+
+#[cfg(feature = "std")]
+impl Display for ParseOneBasedOsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseOneBasedOsError::NotUnicode(s) => write!(f, "index is not valid Unicode: {:?}", s),
+            ParseOneBasedOsError::InvalidNumber(e) => write!(f, "invalid index: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseOneBasedOsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseOneBasedOsError::NotUnicode(_) => None,
+            ParseOneBasedOsError::InvalidNumber(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ParseIntError> for ParseOneBasedOsError {
+    fn from(value: ParseIntError) -> Self {
+        ParseOneBasedOsError::InvalidNumber(value)
+    }
+}
+
+/// Buffer length for the significant digits in [`OneBased::parse_lenient`].
+/// One more than [`MAX_DIGITS`], so that a value beyond `u128` keeps
+/// an extra digit and is reported as overflow instead of being truncated.
+const LENIENT_DIGITS: usize = MAX_DIGITS + 1;
+
+impl<T: UnsignedNonZero> OneBased<T> {
+    /// Parses the 1-based value same as [`FromStr`](core::str::FromStr),
+    /// but returns [`ParseOneBasedError`] suitable to show to end users.
+    ///
+    /// This is the parser to use for the user input.
+    /// [`FromStr`](core::str::FromStr) still returns [`ParseIntError`] to stay interchangeable with the primitive integers.
+    ///
+    /// ```
+    /// # use one_based::OneBasedU32;
+    /// let v = OneBasedU32::parse_index("12")?;
+    /// assert_eq!(v.as_zero_based(), 11);
+    /// # Ok::<(), one_based::ParseOneBasedError>(())
+    /// ```
+    pub fn parse_index(s: &str) -> Result<Self, ParseOneBasedError> {
+        s.parse().map_err(|e| ParseOneBasedError::new(s, &e))
+    }
+
+    /// Parses the command line value for [`argh`], to be used with `from_str_fn`.
+    ///
+    /// While `OneBased*` types already implement [`argh::FromArgValue`] through [`FromStr`](core::str::FromStr),
+    /// this function reports friendly error message same as [`ParseOneBasedError`].
+    ///
+    /// ```
+    /// # use one_based::OneBasedU32;
+    /// use argh::FromArgs;
+    ///
+    /// /// Shows the line.
+    /// #[derive(FromArgs)]
+    /// struct Args {
+    ///     /// line number
+    ///     #[argh(option, from_str_fn(OneBasedU32::parse_arg))]
+    ///     line: OneBasedU32,
+    /// }
+    ///
+    /// let args = Args::from_args(&["show"], &["--line", "3"]).unwrap();
+    /// assert_eq!(args.line.as_zero_based(), 2);
+    ///
+    /// let err = Args::from_args(&["show"], &["--line", "0"]).err().unwrap();
+    /// assert!(err.output.contains("indices start at 1"));
+    /// ```
+    #[cfg(feature = "argh")]
+    pub fn parse_arg(value: &str) -> Result<Self, std::string::String> {
+        Self::parse_index(value).map_err(|e| std::string::ToString::to_string(&e))
+    }
+
+    /// Parses the 1-based value leniently, for the hand-edited inputs.
+    /// Unlike [`FromStr`](core::str::FromStr), this function trims the surrounding whitespace,
+    /// accepts a leading `+`, and ignores `_` digit separators.
+    ///
+    /// ```
+    /// # use one_based::OneBasedU32;
+    /// let v = OneBasedU32::parse_lenient(" +1_000 ")?;
+    /// assert_eq!(v.as_zero_based(), 999);
+    /// assert!(OneBasedU32::parse_lenient("0_0").is_err());
+    /// # Ok::<(), core::num::ParseIntError>(())
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self, ParseIntError> {
+        let s = s.trim();
+        let digits = s.strip_prefix('+').unwrap_or(s);
+        let mut buf = [0u8; LENIENT_DIGITS];
+        let mut len = 0;
+        let mut has_digit = false;
+        for c in digits.bytes() {
+            match c {
+                b'_' => (),
+                // skip leading zeros so that the buffer only holds significant digits.
+                b'0' if len == 0 => has_digit = true,
+                b'0'..=b'9' => {
+                    has_digit = true;
+                    if len < LENIENT_DIGITS {
+                        buf[len] = c;
+                        len += 1;
+                    }
+                }
+                _ => return Err(invalid_digit_error()),
+            }
+        }
+        match (has_digit, len) {
+            // let the regular parser report the error, such as empty input.
+            (false, _) => s.parse(),
+            (true, 0) => Err(zero_error()),
+            // buf only contains ASCII digits.
+            (true, _) => core::str::from_utf8(&buf[..len])
+                .map_err(|_| invalid_digit_error())?
+                .parse(),
+        }
+    }
+
+    /// Parses the 1-based value strictly, for the protocol grammar such as IMAP.
+    /// Unlike [`FromStr`](core::str::FromStr), this function rejects a leading `+` and leading zeros.
+    /// Surrounding whitespace is rejected as well.
+    ///
+    /// ```
+    /// # use one_based::OneBasedU32;
+    /// let v = OneBasedU32::parse_strict("10")?;
+    /// assert_eq!(v.as_zero_based(), 9);
+    /// assert!(OneBasedU32::parse_strict("010").is_err());
+    /// assert!(OneBasedU32::parse_strict("+10").is_err());
+    /// # Ok::<(), core::num::ParseIntError>(())
+    /// ```
+    pub fn parse_strict(s: &str) -> Result<Self, ParseIntError> {
+        match s.as_bytes() {
+            [b'+', ..] | [b'0', _, ..] => Err(invalid_digit_error()),
+            _ => s.parse(),
+        }
+    }
+
+    /// Parses the 1-based value from [`OsStr`](std::ffi::OsStr), such as command line arguments.
+    /// Returns error if the input is not valid Unicode, without lossy conversion.
+    ///
+    /// ```
+    /// # use one_based::OneBasedU32;
+    /// use std::ffi::OsStr;
+    ///
+    /// let v = OneBasedU32::from_os_str(OsStr::new("3"))?;
+    /// assert_eq!(v.as_zero_based(), 2);
+    /// # Ok::<(), one_based::ParseOneBasedOsError>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_os_str(s: &std::ffi::OsStr) -> Result<Self, ParseOneBasedOsError> {
+        let s = s
+            .to_str()
+            .ok_or_else(|| ParseOneBasedOsError::NotUnicode(s.to_os_string()))?;
+        Ok(s.parse()?)
+    }
+}
+
+/// Splits the given ordinal string into the digits and the suffix.
+pub(crate) fn split_ordinal(s: &str) -> (&str, &str) {
+    let pos = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    s.split_at(pos)
+}
+
+/// Returns [`ParseIntError`] of [`core::num::IntErrorKind::Zero`],
+/// which is what `NonZero*` parser returns for "0".
+pub(crate) fn zero_error() -> ParseIntError {
+    "0".parse::<core::num::NonZeroU8>().unwrap_err()
+}
+
+/// Returns [`ParseIntError`] of [`core::num::IntErrorKind::InvalidDigit`].
+pub(crate) fn invalid_digit_error() -> ParseIntError {
+    "-".parse::<u8>().unwrap_err()
+}
+
+/// Returns [`ParseIntError`] of [`core::num::IntErrorKind::Empty`].
+pub(crate) fn empty_error() -> ParseIntError {
+    "".parse::<u8>().unwrap_err()
+}
+
+/// Returns [`ParseIntError`] of [`core::num::IntErrorKind::PosOverflow`].
+pub(crate) fn overflow_error() -> ParseIntError {
+    "256".parse::<u8>().unwrap_err()
+}
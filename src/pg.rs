@@ -0,0 +1,76 @@
+//! Provides [`postgres_types`] integration, used by `postgres` and `tokio-postgres`.
+//!
+//! `OneBased*` types are stored as the 1-based value in `INT2`, `INT4` or `INT8` column.
+//! Natural choice is `INT4` for `OneBasedU8` and `OneBasedU16`,
+//! and `INT8` for the wider types.
+//! Serialization fails if the value doesn't fit in the column type,
+//! and deserialization fails on zero, negative or too large values.
+
+use core::convert::TryFrom;
+use std::error::Error;
+
+use ::bytes::BytesMut;
+use ::postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::{OneBasedU128, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize};
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+/// Writes the 1-based value into the integer column of the given type.
+fn write_i64(v: i64, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+    match *ty {
+        Type::INT2 => i16::try_from(v)?.to_sql(ty, out),
+        Type::INT4 => i32::try_from(v)?.to_sql(ty, out),
+        _ => v.to_sql(ty, out),
+    }
+}
+
+/// Reads the 1-based value from the integer column of the given type.
+fn read_i64(ty: &Type, raw: &[u8]) -> Result<i64, BoxError> {
+    match *ty {
+        Type::INT2 => i16::from_sql(ty, raw).map(Into::into),
+        Type::INT4 => i32::from_sql(ty, raw).map(Into::into),
+        _ => i64::from_sql(ty, raw),
+    }
+}
+
+macro_rules! impl_postgres_types {
+    (@common $name:ident) => {
+        impl<'a> FromSql<'a> for $name {
+            fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+                Ok(<$name>::try_from_db_i64(read_i64(ty, raw)?)?)
+            }
+
+            accepts!(INT2, INT4, INT8);
+        }
+    };
+    (lossless: $($name:ident),+) => {$(
+        impl_postgres_types!(@common $name);
+
+        impl ToSql for $name {
+            fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+                write_i64(self.as_db_i64(), ty, out)
+            }
+
+            accepts!(INT2, INT4, INT8);
+
+            to_sql_checked!();
+        }
+    )*};
+    (fallible: $($name:ident),+) => {$(
+        impl_postgres_types!(@common $name);
+
+        impl ToSql for $name {
+            fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+                write_i64(self.try_as_db_i64()?, ty, out)
+            }
+
+            accepts!(INT2, INT4, INT8);
+
+            to_sql_checked!();
+        }
+    )*};
+}
+
+impl_postgres_types!(lossless: OneBasedU8, OneBasedU16, OneBasedU32);
+impl_postgres_types!(fallible: OneBasedU64, OneBasedU128, OneBasedUsize);
@@ -0,0 +1,70 @@
+//! Provides [`proptest`](::proptest) integration.
+//!
+//! `OneBased*` types implement [`Arbitrary`], generating any valid index `1..=MAX`,
+//! and this module provides strategies to generate them from the given 1-based range.
+//! The generated values shrink toward 1.
+//!
+//! ```
+//! use one_based::{proptest::one_based_range, OneBasedU32};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(line in one_based_range::<OneBasedU32>(1..=100), column: OneBasedU32)| {
+//!     prop_assert!(line.as_zero_based() < 100);
+//!     prop_assert_ne!(column.as_one_based().get(), 0);
+//! });
+//! ```
+
+use core::ops::RangeInclusive;
+
+use ::proptest::{
+    arbitrary::{any, Arbitrary},
+    strategy::{Map, Strategy},
+};
+
+use crate::{
+    OneBasedInteger, OneBasedU128, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize,
+};
+
+/// Returns the strategy to generate `OneBased*` values in the given 1-based range.
+///
+/// # Panics
+///
+/// Panics if the range starts from 0.
+pub fn one_based_range<T>(range: RangeInclusive<T::Int>) -> impl Strategy<Value = T>
+where
+    T: OneBasedInteger,
+    RangeInclusive<T::Int>: Strategy<Value = T::Int>,
+{
+    assert!(
+        T::from_one_based_opt(*range.start()).is_some(),
+        "1-based range must start from 1"
+    );
+    range.prop_map(|v| T::from_one_based_opt(v).expect("must be non-zero"))
+}
+
+macro_rules! impl_proptest {
+    ($($name:ident, $itype:ty, $any:ident);+) => {$(
+        impl Arbitrary for $name {
+            type Parameters = ();
+            type Strategy = Map<RangeInclusive<$itype>, fn($itype) -> Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                (1..=<$itype>::MAX).prop_map(|v| <$name>::from_one_based_opt(v).expect("must be non-zero"))
+            }
+        }
+
+        #[doc = concat!(r"Returns the strategy to generate any [`", stringify!($name), r"`] value.")]
+        pub fn $any() -> <$name as Arbitrary>::Strategy {
+            any::<$name>()
+        }
+    )*};
+}
+
+impl_proptest!(
+    OneBasedU8, u8, any_one_based_u8;
+    OneBasedU16, u16, any_one_based_u16;
+    OneBasedU32, u32, any_one_based_u32;
+    OneBasedU64, u64, any_one_based_u64;
+    OneBasedU128, u128, any_one_based_u128;
+    OneBasedUsize, usize, any_one_based_usize
+);
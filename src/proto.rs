@@ -0,0 +1,93 @@
+//! Provides helpers to map Protocol Buffers fields generated by `prost`.
+//!
+//! Proto3 has no 1-based integer type, and unset scalar field is read as 0.
+//! The helpers here convert `uint32` and `uint64` fields to `OneBased*` types,
+//! treating 0 either as an error for required fields, or as `None` for optional fields.
+//!
+//! ```
+//! # use one_based::{OneBasedError, OneBasedU32};
+//! use std::convert::TryFrom;
+//!
+//! #[derive(Clone, PartialEq, prost::Message)]
+//! struct CursorProto {
+//!     #[prost(uint32, tag = "1")]
+//!     line: u32,
+//!     #[prost(uint32, tag = "2")]
+//!     column: u32,
+//! }
+//!
+//! struct Cursor {
+//!     line: OneBasedU32,
+//!     column: Option<OneBasedU32>,
+//! }
+//!
+//! impl TryFrom<CursorProto> for Cursor {
+//!     type Error = OneBasedError;
+//!
+//!     fn try_from(value: CursorProto) -> Result<Self, Self::Error> {
+//!         Ok(Cursor {
+//!             line: OneBasedU32::from_proto_field(value.line)?,
+//!             column: OneBasedU32::from_proto_optional(value.column),
+//!         })
+//!     }
+//! }
+//!
+//! impl From<Cursor> for CursorProto {
+//!     fn from(value: Cursor) -> Self {
+//!         CursorProto {
+//!             line: value.line.to_proto_field(),
+//!             column: OneBasedU32::to_proto_optional(value.column),
+//!         }
+//!     }
+//! }
+//!
+//! let cursor = Cursor::try_from(CursorProto { line: 3, column: 0 })?;
+//! assert_eq!(cursor.line.as_zero_based(), 2);
+//! assert_eq!(cursor.column, None);
+//! assert_eq!(CursorProto::from(cursor), CursorProto { line: 3, column: 0 });
+//!
+//! let unset = CursorProto::default();
+//! assert!(Cursor::try_from(unset).is_err());
+//! # Ok::<(), OneBasedError>(())
+//! ```
+
+use crate::{OneBasedError, OneBasedU32, OneBasedU64};
+
+macro_rules! impl_proto_field {
+    ($($name:ident, $itype:ty, $proto:literal);+) => {$(
+        impl $name {
+            #[doc = concat!(r"Creates `", stringify!($name), r"` from 1-based `", $proto, r"` field, which is required to be set.")]
+            /// Returns [`OneBasedError::ZeroIndex`] if the field is 0, i.e. unset.
+            #[inline]
+            pub const fn from_proto_field(v: $itype) -> Result<Self, OneBasedError> {
+                Self::from_one_based(v)
+            }
+
+            #[doc = concat!(r"Creates `", stringify!($name), r"` from 1-based `", $proto, r"` field, where 0 means unset.")]
+            #[inline]
+            pub const fn from_proto_optional(v: $itype) -> Option<Self> {
+                Self::from_one_based_opt(v)
+            }
+
+            #[doc = concat!(r"Returns 1-based value to be set to `", $proto, r"` field.")]
+            #[inline]
+            pub const fn to_proto_field(self) -> $itype {
+                self.as_one_based().get()
+            }
+
+            #[doc = concat!(r"Returns 1-based value to be set to `", $proto, r"` field, where `None` is encoded as 0.")]
+            #[inline]
+            pub const fn to_proto_optional(v: Option<Self>) -> $itype {
+                match v {
+                    Some(v) => v.to_proto_field(),
+                    None => 0,
+                }
+            }
+        }
+    )*};
+}
+
+impl_proto_field!(
+    OneBasedU32, u32, "uint32";
+    OneBasedU64, u64, "uint64"
+);
@@ -0,0 +1,91 @@
+//! Provides [`pyo3`] integration.
+//!
+//! `OneBased*` types are converted to Python `int` holding the 1-based value.
+//! Extracting zero or negative `int` raises `ValueError`,
+//! while too large `int` raises `OverflowError` same as the primitive integers.
+//!
+//! ```
+//! # use one_based::OneBasedU32;
+//! use pyo3::prelude::*;
+//!
+//! #[pyfunction]
+//! fn offset(page: OneBasedU32) -> u32 {
+//!     page.as_zero_based() * 10
+//! }
+//!
+//! Python::attach(|py| {
+//!     let f = wrap_pyfunction!(offset, py).unwrap();
+//!     assert_eq!(f.call1((2,)).unwrap().extract::<u32>().unwrap(), 10);
+//!
+//!     let err = f.call1((0,)).unwrap_err();
+//!     assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+//! });
+//! ```
+
+use core::convert::Infallible;
+
+use ::pyo3::{
+    exceptions::PyValueError, types::PyInt, Borrowed, Bound, FromPyObject, IntoPyObject, PyAny,
+    PyErr, PyResult, Python,
+};
+
+use crate::{
+    OneBasedError, OneBasedU128, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize,
+};
+
+impl From<OneBasedError> for PyErr {
+    /// Converts into Python `ValueError`.
+    fn from(e: OneBasedError) -> Self {
+        PyValueError::new_err(e.to_string())
+    }
+}
+
+macro_rules! impl_pyo3 {
+    ($($name:ident, $itype:ty);+) => {$(
+        impl<'a, 'py> FromPyObject<'a, 'py> for $name {
+            type Error = PyErr;
+
+            fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+                match obj.extract::<$itype>() {
+                    Ok(v) => Ok(<$name>::from_one_based(v)?),
+                    // primitive extraction reports OverflowError on negative values.
+                    Err(e) => match obj.extract::<i64>() {
+                        Ok(v) if v < 0 => Err(OneBasedError::NegativeIndex { value: v.into() }.into()),
+                        _ => Err(e),
+                    },
+                }
+            }
+        }
+
+        impl<'py> IntoPyObject<'py> for $name {
+            type Target = PyInt;
+            type Output = Bound<'py, PyInt>;
+            type Error = Infallible;
+
+            #[doc = concat!(r"Converts [`", stringify!($name), r"`] to 1-based Python `int`.")]
+            fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+                self.as_one_based().get().into_pyobject(py)
+            }
+        }
+
+        impl<'py> IntoPyObject<'py> for &$name {
+            type Target = PyInt;
+            type Output = Bound<'py, PyInt>;
+            type Error = Infallible;
+
+            #[doc = concat!(r"Converts [`", stringify!($name), r"`] to 1-based Python `int`.")]
+            fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+                (*self).into_pyobject(py)
+            }
+        }
+    )*};
+}
+
+impl_pyo3!(
+    OneBasedU8, u8;
+    OneBasedU16, u16;
+    OneBasedU32, u32;
+    OneBasedU64, u64;
+    OneBasedU128, u128;
+    OneBasedUsize, usize
+);
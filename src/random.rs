@@ -0,0 +1,129 @@
+//! Provides [`rand`] integration.
+//!
+//! `OneBased*` types can be sampled uniformly from the given range,
+//! or from the whole valid range `1..=MAX` with [`StandardUniform`].
+//!
+//! ```
+//! # use one_based::{OneBasedU32, OneBasedUsize};
+//! use rand::Rng;
+//!
+//! let mut rng = rand::rng();
+//! let page: OneBasedU32 = rng.random_range(OneBasedU32::FIRST..=OneBasedU32::from_one_based(10)?);
+//! assert!(page.as_zero_based() < 10);
+//!
+//! let items = ["apple", "banana", "cherry"];
+//! let i = OneBasedUsize::sample_in(&mut rng, items.len()).unwrap();
+//! assert!(items.get(i.as_zero_based()).is_some());
+//! # Ok::<(), one_based::OneBasedError>(())
+//! ```
+
+use core::fmt::Debug;
+
+use ::rand::{
+    distr::{
+        uniform::{Error, SampleBorrow, SampleUniform, UniformSampler},
+        Distribution, StandardUniform,
+    },
+    Rng,
+};
+
+use crate::{OneBased, OneBasedInteger, OneBasedUsize, UnsignedNonZero};
+
+/// Uniform sampler of `OneBased*` types, used through [`SampleUniform`].
+pub struct UniformOneBased<T>(<T::Int as SampleUniform>::Sampler)
+where
+    T: OneBasedInteger,
+    T::Int: SampleUniform;
+
+impl<T> Debug for UniformOneBased<T>
+where
+    T: OneBasedInteger,
+    T::Int: SampleUniform,
+    <T::Int as SampleUniform>::Sampler: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("UniformOneBased").field(&self.0).finish()
+    }
+}
+
+impl<T> Clone for UniformOneBased<T>
+where
+    T: OneBasedInteger,
+    T::Int: SampleUniform,
+    <T::Int as SampleUniform>::Sampler: Clone,
+{
+    fn clone(&self) -> Self {
+        UniformOneBased(self.0.clone())
+    }
+}
+
+impl<T> Copy for UniformOneBased<T>
+where
+    T: OneBasedInteger,
+    T::Int: SampleUniform,
+    <T::Int as SampleUniform>::Sampler: Copy,
+{
+}
+
+/// Returns 1-based value of the borrowed `OneBased*`.
+fn one_based<T: OneBasedInteger, B: SampleBorrow<T>>(v: B) -> T::Int {
+    v.borrow().as_one_based().get()
+}
+
+impl<T> UniformSampler for UniformOneBased<T>
+where
+    T: OneBasedInteger,
+    T::Int: SampleUniform,
+{
+    type X = T;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformSampler::new(one_based(low), one_based(high)).map(UniformOneBased)
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformSampler::new_inclusive(one_based(low), one_based(high)).map(UniformOneBased)
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        T::from_one_based_opt(self.0.sample(rng)).expect("sampled value must be within the range")
+    }
+}
+
+impl<T> SampleUniform for OneBased<T>
+where
+    T: UnsignedNonZero,
+    Self: OneBasedInteger,
+    <Self as OneBasedInteger>::Int: SampleUniform,
+{
+    type Sampler = UniformOneBased<Self>;
+}
+
+impl<T> Distribution<OneBased<T>> for StandardUniform
+where
+    T: UnsignedNonZero,
+    OneBased<T>: OneBasedInteger,
+    <OneBased<T> as OneBasedInteger>::Int: SampleUniform,
+{
+    /// Samples uniformly from all the valid values `1..=MAX`.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> OneBased<T> {
+        rng.random_range(OneBased::<T>::FIRST..=OneBased::<T>::MAX)
+    }
+}
+
+impl OneBasedUsize {
+    /// Returns a random valid index of the collection of the given length.
+    /// Returns `None` if the collection is empty.
+    pub fn sample_in<R: Rng + ?Sized>(rng: &mut R, len: usize) -> Option<Self> {
+        let last = OneBasedUsize::from_one_based(len).ok()?;
+        Some(rng.random_range(OneBasedUsize::FIRST..=last))
+    }
+}
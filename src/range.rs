@@ -0,0 +1,496 @@
+//! Provides inclusive range of `OneBased*` types.
+
+use core::{
+    fmt::Display,
+    iter::FusedIterator,
+    num::{NonZeroUsize, ParseIntError},
+    ops::{Bound, RangeBounds},
+    str::FromStr,
+};
+
+use crate::{
+    OneBased, OneBasedError, OneBasedInteger, OneBasedU128, OneBasedU16, OneBasedU32, OneBasedU64,
+    OneBasedU8, OneBasedUsize, UnsignedNonZero,
+};
+
+/// Inclusive range `start..=end` of 1-based indices, such as line numbers "3-7".
+///
+/// Iterating the range yields the typed indices.
+///
+/// ```
+/// # use one_based::{OneBasedRange, OneBasedUsize};
+/// let lines: OneBasedRange<OneBasedUsize> = "3-5".parse().unwrap();
+/// assert_eq!(lines.len(), 3);
+/// assert!(lines.contains(OneBasedUsize::from_one_based(4).unwrap()));
+/// assert_eq!(lines.to_string(), "3-5");
+///
+/// let zero_based: Vec<usize> = lines.map(|line| line.as_zero_based()).collect();
+/// assert_eq!(zero_based, [2, 3, 4]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct OneBasedRange<T: OneBasedInteger> {
+    /// 0-based inclusive start, which is always smaller than `MAX` of the integer.
+    start: T::Int,
+    /// 0-based exclusive end, that is the 1-based inclusive end, which is never zero.
+    end: T::Int,
+}
+
+impl<T: OneBasedInteger> OneBasedRange<T> {
+    /// Creates the range `start..=end`.
+    /// The range is empty if `start > end`.
+    #[inline]
+    pub fn new(start: T, end: T) -> Self {
+        Self {
+            start: start.as_zero_based(),
+            end: end.as_one_based().get(),
+        }
+    }
+
+    /// Returns the lower bound of the range.
+    ///
+    /// Once the range is exhausted by iteration, this is next to [`end`](Self::end).
+    #[inline]
+    pub fn start(&self) -> T {
+        T::from_zero_based_opt(self.start).expect("start must be smaller than MAX")
+    }
+
+    /// Returns the upper bound of the range.
+    #[inline]
+    pub fn end(&self) -> T {
+        T::from_one_based_opt(self.end).expect("end must be non-zero")
+    }
+
+    /// Returns `true` if the range contains no index.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Returns `true` if the given index is within the range.
+    #[inline]
+    pub fn contains(&self, index: T) -> bool {
+        let index = index.as_zero_based();
+        self.start <= index && index < self.end
+    }
+
+    /// Returns the iterator yielding every `step`-th index, starting from the first one.
+    ///
+    /// Unlike [`Iterator::step_by`], this takes the non-zero step and skips the indices without iterating them.
+    ///
+    /// ```
+    /// # use core::num::NonZeroUsize;
+    /// # use one_based::{OneBasedRange, OneBasedU32};
+    /// let lines = OneBasedRange::new(OneBasedU32::FIRST, OneBasedU32::from_one_based(25)?);
+    /// let every_10th = lines.step_by(NonZeroUsize::new(10).unwrap());
+    /// let lines: Vec<u32> = every_10th.map(|line| line.get()).collect();
+    /// assert_eq!(lines, [1, 11, 21]);
+    /// # Ok::<(), one_based::OneBasedError>(())
+    /// ```
+    #[inline]
+    pub fn step_by(self, step: NonZeroUsize) -> OneBasedStepBy<T> {
+        OneBasedStepBy {
+            range: self,
+            step,
+            first_taken: false,
+        }
+    }
+}
+
+impl<T: OneBasedInteger> core::fmt::Debug for OneBasedRange<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OneBasedRange")
+            .field("start", &self.start())
+            .field("end", &self.end())
+            .finish()
+    }
+}
+
+impl OneBasedRange<OneBasedUsize> {
+    /// Returns the corresponding 0-based half-open range,
+    /// which can index slices with [`slice::get`] in addition to [`RangeBounds`].
+    ///
+    /// ```
+    /// # use one_based::{OneBasedRange, OneBasedUsize};
+    /// let lines: OneBasedRange<OneBasedUsize> = "2-3".parse().unwrap();
+    /// assert_eq!(lines.as_zero_based(), 1..3);
+    ///
+    /// let reversed = OneBasedRange::new(lines.end(), OneBasedUsize::FIRST);
+    /// assert_eq!(reversed.as_zero_based(), 1..1);
+    ///
+    /// let v = ["a", "b", "c", "d"];
+    /// assert_eq!(v.get(lines.as_zero_based()), Some(&["b", "c"][..]));
+    /// ```
+    #[inline]
+    pub const fn as_zero_based(&self) -> core::ops::Range<usize> {
+        // empty range maps to `end..end`, so that it never becomes reversed.
+        if self.start >= self.end {
+            self.end..self.end
+        } else {
+            self.start..self.end
+        }
+    }
+}
+
+/// Bounds of the corresponding 0-based half-open range,
+/// so that the range can be passed to [`Vec::drain`] and similar APIs directly.
+///
+/// ```
+/// # use one_based::{OneBasedRange, OneBasedUsize};
+/// let lines: OneBasedRange<OneBasedUsize> = "2-3".parse().unwrap();
+/// let mut v = vec!["a", "b", "c", "d"];
+/// assert_eq!(v.drain(lines).collect::<Vec<_>>(), ["b", "c"]);
+/// assert_eq!(v, ["a", "d"]);
+/// ```
+impl RangeBounds<usize> for OneBasedRange<OneBasedUsize> {
+    #[inline]
+    fn start_bound(&self) -> Bound<&usize> {
+        // same as `as_zero_based`, empty range starts at `end`.
+        if self.is_empty() {
+            Bound::Included(&self.end)
+        } else {
+            Bound::Included(&self.start)
+        }
+    }
+
+    #[inline]
+    fn end_bound(&self) -> Bound<&usize> {
+        Bound::Excluded(&self.end)
+    }
+}
+
+impl From<OneBasedRange<OneBasedUsize>> for core::ops::Range<usize> {
+    #[inline]
+    fn from(range: OneBasedRange<OneBasedUsize>) -> Self {
+        range.as_zero_based()
+    }
+}
+
+/// Returns the index next to the given one.
+/// Returns [`OneBasedError::OverflowIndex`] if it's `MAX`.
+pub(crate) fn succ<T: OneBasedInteger>(v: T) -> Result<T, OneBasedError> {
+    // 0-based value equal to the 1-based value of `v` denotes the next index.
+    T::from_zero_based(v.as_one_based().get())
+}
+
+/// Returns the index prior to the given one, or `None` if it's 1.
+pub(crate) fn pred<T: OneBasedInteger>(v: T) -> Option<T> {
+    T::from_one_based_opt(v.as_zero_based())
+}
+
+impl<T: OneBasedInteger> OneBasedRange<T> {
+    /// Makes the range empty, keeping `start` next to `end`.
+    fn exhaust(&mut self) {
+        if T::from_zero_based_opt(self.end).is_none() {
+            // `start` can't go beyond `MAX`, so shrink `end` instead.
+            self.end = T::MAX.as_zero_based();
+        }
+        self.start = self.end;
+    }
+
+    fn next_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let v = self.start();
+        self.start = v.as_one_based().get();
+        if self.is_empty() {
+            self.exhaust();
+        }
+        Some(v)
+    }
+
+    fn next_end(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let v = self.end();
+        match pred(v) {
+            Some(prev) => self.end = prev.as_one_based().get(),
+            None => self.exhaust(),
+        }
+        Some(v)
+    }
+}
+
+macro_rules! impl_range_iter {
+    ($($name:ty, $itype:ty);+) => {$(
+        impl OneBasedRange<$name> {
+            /// Returns the number of the indices in the range, or `None` if it doesn't fit in `usize`.
+            #[inline]
+            pub fn checked_len(&self) -> Option<usize> {
+                use core::convert::TryFrom as _;
+                if self.is_empty() {
+                    return Some(0);
+                }
+                usize::try_from(self.end - self.start).ok()
+            }
+
+            /// Returns the number of the indices in the range.
+            ///
+            /// This is available even where [`ExactSizeIterator`] isn't implemented,
+            /// such as 128-bit ranges.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the length doesn't fit in `usize`. Use [`checked_len`](Self::checked_len) to avoid it.
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.checked_len().expect("range length must fit in usize")
+            }
+        }
+
+        impl Iterator for OneBasedRange<$name> {
+            type Item = $name;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.next_front()
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match self.checked_len() {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                }
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                match self.checked_len() {
+                    Some(len) if n >= len => {
+                        self.exhaust();
+                        return None;
+                    }
+                    // the length beyond `usize` is always larger than `n`.
+                    _ => (),
+                }
+                // `n` is smaller than the length, so `start` stays below `end`.
+                self.start += n as $itype;
+                self.next()
+            }
+        }
+
+        impl DoubleEndedIterator for OneBasedRange<$name> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.next_end()
+            }
+        }
+
+        impl FusedIterator for OneBasedRange<$name> {}
+    )*};
+}
+
+impl_range_iter!(
+    OneBasedU8, u8;
+    OneBasedU16, u16;
+    OneBasedU32, u32;
+    OneBasedU64, u64;
+    OneBasedU128, u128;
+    OneBasedUsize, usize
+);
+
+// The length never exceeds `MAX`, which fits in `usize` for these types.
+impl ExactSizeIterator for OneBasedRange<OneBasedU8> {}
+impl ExactSizeIterator for OneBasedRange<OneBasedU16> {}
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ExactSizeIterator for OneBasedRange<OneBasedU32> {}
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for OneBasedRange<OneBasedU64> {}
+impl ExactSizeIterator for OneBasedRange<OneBasedUsize> {}
+
+impl<T: OneBasedInteger> Display for OneBasedRange<T> {
+    /// Formats as "3-7", which [`FromStr`] parses back.
+    ///
+    /// An empty range has no such form, so it's formatted with the reversed bounds,
+    /// for example "6-5" once "3-5" is exhausted, which [`FromStr`] rejects as [`ParseRangeError::Reversed`].
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}-{}", self.start(), self.end())
+    }
+}
+
+/// Iterator yielding every `step`-th index of [`OneBasedRange`].
+///
+/// This is created by [`OneBasedRange::step_by`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OneBasedStepBy<T: OneBasedInteger> {
+    range: OneBasedRange<T>,
+    step: NonZeroUsize,
+    first_taken: bool,
+}
+
+impl<T: OneBasedInteger> Iterator for OneBasedStepBy<T>
+where
+    OneBasedRange<T>: Iterator<Item = T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first_taken {
+            self.range.nth(self.step.get() - 1)
+        } else {
+            self.first_taken = true;
+            self.range.next()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let step = self.step.get();
+        let count = |len: usize| {
+            if self.first_taken {
+                len / step
+            } else if len == 0 {
+                0
+            } else {
+                1 + (len - 1) / step
+            }
+        };
+        let (lower, upper) = self.range.size_hint();
+        (count(lower), upper.map(count))
+    }
+}
+
+impl<T: OneBasedInteger> FusedIterator for OneBasedStepBy<T> where
+    OneBasedRange<T>: FusedIterator<Item = T>
+{
+}
+
+/// Error type used when parsing [`OneBasedRange`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRangeError {
+    /// The start index is invalid.
+    InvalidStart(ParseIntError),
+    /// The end index is invalid.
+    InvalidEnd(ParseIntError),
+    /// The end index is smaller than the start index.
+    Reversed,
+}
+
+impl Display for ParseRangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseRangeError::InvalidStart(e) => write!(f, "invalid range start: {}", e),
+            ParseRangeError::InvalidEnd(e) => write!(f, "invalid range end: {}", e),
+            ParseRangeError::Reversed => f.write_str("range end is smaller than the start"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRangeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseRangeError::InvalidStart(e) | ParseRangeError::InvalidEnd(e) => Some(e),
+            ParseRangeError::Reversed => None,
+        }
+    }
+}
+
+impl<T: OneBasedInteger> FromStr for OneBasedRange<T> {
+    type Err = ParseRangeError;
+
+    /// Parses "3-7" form, or a single index "5" as the range of the index.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s.split_once('-').unwrap_or((s, s));
+        let start: T = start.parse().map_err(ParseRangeError::InvalidStart)?;
+        let end: T = end.parse().map_err(ParseRangeError::InvalidEnd)?;
+        if end < start {
+            return Err(ParseRangeError::Reversed);
+        }
+        Ok(Self::new(start, end))
+    }
+}
+
+impl<T: UnsignedNonZero> OneBased<T>
+where
+    Self: OneBasedInteger,
+{
+    /// Returns the iterator over successive indices starting from `self`,
+    /// which stops after yielding `MAX` instead of wrapping around.
+    ///
+    /// ```
+    /// # use one_based::OneBasedU8;
+    /// let mut records = OneBasedU8::from_one_based(254)?.iter_from();
+    /// assert_eq!(records.next().map(|v| v.get()), Some(254));
+    /// assert_eq!(records.next().map(|v| v.get()), Some(255));
+    /// assert_eq!(records.next(), None);
+    /// # Ok::<(), one_based::OneBasedError>(())
+    /// ```
+    #[inline]
+    pub fn iter_from(self) -> OneBasedRange<Self> {
+        OneBasedRange::new(self, <Self as OneBasedInteger>::MAX)
+    }
+
+    /// Returns the inclusive range `self..=end`, which iterates the typed indices.
+    ///
+    /// ```
+    /// # use one_based::OneBasedUsize;
+    /// let first = OneBasedUsize::from_one_based(3)?;
+    /// let last = OneBasedUsize::from_one_based(5)?;
+    /// let mut lines = Vec::new();
+    /// for line in first.iter_to(last) {
+    ///     lines.push(line.get());
+    /// }
+    /// assert_eq!(lines, [3, 4, 5]);
+    /// # Ok::<(), one_based::OneBasedError>(())
+    /// ```
+    #[inline]
+    pub fn iter_to(self, end: Self) -> OneBasedRange<Self> {
+        OneBasedRange::new(self, end)
+    }
+
+    /// Returns the iterator over successive indices starting from `self`,
+    /// which yields [`OneBasedError::OverflowIndex`] once after `MAX`.
+    ///
+    /// ```
+    /// # use one_based::{OneBasedError, OneBasedU8};
+    /// let mut records = OneBasedU8::MAX.checked_iter_from();
+    /// assert_eq!(records.next(), Some(Ok(OneBasedU8::MAX)));
+    /// assert_eq!(
+    ///     records.next(),
+    ///     Some(Err(OneBasedError::OverflowIndex { value: 255, bits: 8 }))
+    /// );
+    /// assert_eq!(records.next(), None);
+    /// ```
+    #[inline]
+    pub fn checked_iter_from(self) -> CheckedIterFrom<Self> {
+        CheckedIterFrom {
+            next: Some(Ok(self)),
+        }
+    }
+}
+
+/// Iterator over successive indices, which reports error after `MAX`.
+///
+/// This is created by `checked_iter_from` method of `OneBased*` types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckedIterFrom<T> {
+    next: Option<Result<T, OneBasedError>>,
+}
+
+impl<T: OneBasedInteger> Iterator for CheckedIterFrom<T> {
+    type Item = Result<T, OneBasedError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        if let Ok(v) = current {
+            self.next = Some(succ(v));
+        }
+        Some(current)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(Ok(_)) => (1, None),
+            Some(Err(_)) => (1, Some(1)),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<T: OneBasedInteger> FusedIterator for CheckedIterFrom<T> {}
@@ -0,0 +1,220 @@
+//! Provides set of `OneBased*` indices stored as disjoint ranges.
+
+use core::fmt::Display;
+
+use crate::{
+    range::{pred, succ},
+    OneBasedInteger, OneBasedRange,
+};
+
+/// Set of 1-based indices, stored compactly as the sorted disjoint ranges,
+/// such as pages already downloaded.
+///
+/// ```
+/// # use one_based::{OneBasedRangeSet, OneBasedU32};
+/// let page = |i| OneBasedU32::from_one_based(i).unwrap();
+/// let mut downloaded = OneBasedRangeSet::new();
+/// downloaded.insert(page(1).iter_to(page(3)));
+/// downloaded.insert(page(7).iter_to(page(9)));
+/// downloaded.insert(page(4).iter_to(page(4)));
+/// assert_eq!(downloaded.to_string(), "1-4,7-9");
+///
+/// let missing = downloaded.complement_within(page(10));
+/// assert_eq!(missing.to_string(), "5-6,10");
+/// assert_eq!(missing.iter().map(|p| p.get()).collect::<Vec<_>>(), [5, 6, 10]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OneBasedRangeSet<T> {
+    /// Inclusive ranges sorted in ascending order, which are neither overlapping nor adjacent.
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for OneBasedRangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+/// Returns `true` if the range ending at `end` overlaps or is adjacent to the range starting at `start`.
+fn touches<T: OneBasedInteger>(end: T, start: T) -> bool {
+    succ(end).map_or(true, |next| start <= next)
+}
+
+impl<T: OneBasedInteger> OneBasedRangeSet<T> {
+    /// Creates an empty set.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the set contains no index.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns `true` if the set contains the given index.
+    pub fn contains(&self, index: T) -> bool {
+        let i = self.ranges.partition_point(|&(start, _)| start <= index);
+        i > 0 && index <= self.ranges[i - 1].1
+    }
+
+    /// Adds the indices in the given range to the set.
+    pub fn insert(&mut self, range: OneBasedRange<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (start, end) = (range.start(), range.end());
+        let lo = self.ranges.partition_point(|&(_, e)| !touches(e, start));
+        let hi = self.ranges.partition_point(|&(s, _)| touches(end, s));
+        if lo == hi {
+            self.ranges.insert(lo, (start, end));
+        } else {
+            let merged = (start.min(self.ranges[lo].0), end.max(self.ranges[hi - 1].1));
+            self.ranges.splice(lo..hi, [merged]);
+        }
+    }
+
+    /// Removes the indices in the given range from the set.
+    pub fn remove(&mut self, range: OneBasedRange<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (start, end) = (range.start(), range.end());
+        let lo = self.ranges.partition_point(|&(_, e)| e < start);
+        let hi = self.ranges.partition_point(|&(s, _)| s <= end);
+        if lo == hi {
+            return;
+        }
+        let (first, last) = (self.ranges[lo].0, self.ranges[hi - 1].1);
+        // `first < start` implies `start` has the prior index, and similarly for `end`.
+        let left = pred(start)
+            .filter(|_| first < start)
+            .map(|prev| (first, prev));
+        let right = succ(end)
+            .ok()
+            .filter(|_| end < last)
+            .map(|next| (next, last));
+        self.ranges.splice(lo..hi, left.into_iter().chain(right));
+    }
+
+    /// Returns the set of the indices contained in either `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.ranges() {
+            result.insert(range);
+        }
+        result
+    }
+
+    /// Returns the set of the indices contained in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (s1.max(s2), e1.min(e2));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if e1 < e2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Returns the set of the indices in `1..=max`, which are not contained in `self`.
+    pub fn complement_within(&self, max: T) -> Self {
+        let mut ranges = Vec::new();
+        let mut next = Some(T::FIRST);
+        for &(start, end) in &self.ranges {
+            let gap_start = match next {
+                Some(gap_start) if gap_start <= max => gap_start,
+                _ => break,
+            };
+            if let Some(gap_end) = pred(start) {
+                if gap_start <= gap_end {
+                    ranges.push((gap_start, gap_end.min(max)));
+                }
+            }
+            next = succ(end).ok();
+        }
+        if let Some(gap_start) = next.filter(|&gap_start| gap_start <= max) {
+            ranges.push((gap_start, max));
+        }
+        Self { ranges }
+    }
+
+    /// Returns the iterator over the disjoint ranges in ascending order.
+    #[inline]
+    pub fn ranges(&self) -> RangeSetRanges<'_, T> {
+        RangeSetRanges {
+            ranges: self.ranges.iter(),
+        }
+    }
+
+    /// Returns the iterator over the indices in ascending order.
+    #[inline]
+    pub fn iter(&self) -> core::iter::Flatten<RangeSetRanges<'_, T>>
+    where
+        OneBasedRange<T>: Iterator<Item = T>,
+    {
+        self.ranges().flatten()
+    }
+}
+
+impl<T: OneBasedInteger> Display for OneBasedRangeSet<T> {
+    /// Formats as the comma separated ranges, such as "1-4,7,9-10".
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, &(start, end)) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            if start == end {
+                write!(f, "{}", start)?;
+            } else {
+                write!(f, "{}-{}", start, end)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the ranges of [`OneBasedRangeSet`].
+///
+/// This is created by [`OneBasedRangeSet::ranges`].
+#[derive(Debug, Clone)]
+pub struct RangeSetRanges<'a, T> {
+    ranges: core::slice::Iter<'a, (T, T)>,
+}
+
+impl<T: OneBasedInteger> Iterator for RangeSetRanges<'_, T> {
+    type Item = OneBasedRange<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ranges
+            .next()
+            .map(|&(start, end)| OneBasedRange::new(start, end))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ranges.size_hint()
+    }
+}
+
+impl<T: OneBasedInteger> DoubleEndedIterator for RangeSetRanges<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.ranges
+            .next_back()
+            .map(|&(start, end)| OneBasedRange::new(start, end))
+    }
+}
+
+impl<T: OneBasedInteger> ExactSizeIterator for RangeSetRanges<'_, T> {}
+
+impl<T: OneBasedInteger> core::iter::FusedIterator for RangeSetRanges<'_, T> {}
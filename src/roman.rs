@@ -0,0 +1,142 @@
+//! Provides parser and formatter of Roman numerals such as "XIV".
+
+use core::fmt::Display;
+
+use crate::{
+    fmt::{SliceWriter, MAX_DIGITS},
+    OneBasedInteger,
+};
+
+/// Error type used when parsing Roman numerals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRomanError;
+
+impl Display for ParseRomanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid Roman numeral, expected \"I\" to \"MMMCMXCIX\" in the canonical form")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRomanError {}
+
+/// Largest value which can be written in the standard Roman numerals.
+const MAX_ROMAN: u16 = 3999;
+
+/// Roman numeral symbols in descending order, in upper and lower case.
+const NUMERALS: [(u16, &str, &str); 13] = [
+    (1000, "M", "m"),
+    (900, "CM", "cm"),
+    (500, "D", "d"),
+    (400, "CD", "cd"),
+    (100, "C", "c"),
+    (90, "XC", "xc"),
+    (50, "L", "l"),
+    (40, "XL", "xl"),
+    (10, "X", "x"),
+    (9, "IX", "ix"),
+    (5, "V", "v"),
+    (4, "IV", "iv"),
+    (1, "I", "i"),
+];
+
+/// Calls `f` with each symbol of the canonical Roman numeral of `n`.
+fn for_each_symbol<E>(
+    mut n: u16,
+    lower: bool,
+    mut f: impl FnMut(&'static str) -> Result<(), E>,
+) -> Result<(), E> {
+    for &(value, upper_sym, lower_sym) in NUMERALS.iter() {
+        while n >= value {
+            n -= value;
+            f(if lower { lower_sym } else { upper_sym })?;
+        }
+    }
+    Ok(())
+}
+
+/// Strips the given symbol from the input, ignoring ASCII case.
+fn strip_symbol<'a>(s: &'a [u8], sym: &str) -> Option<&'a [u8]> {
+    let len = sym.len();
+    if s.len() >= len && s[..len].eq_ignore_ascii_case(sym.as_bytes()) {
+        Some(&s[len..])
+    } else {
+        None
+    }
+}
+
+/// Parses the canonical Roman numeral from "I" to "MMMCMXCIX", ignoring ASCII case.
+pub(crate) fn parse_roman(s: &str) -> Result<u16, ParseRomanError> {
+    let mut rest = s.as_bytes();
+    let mut n = 0;
+    for &(value, sym, _) in NUMERALS.iter() {
+        while let Some(r) = strip_symbol(rest, sym) {
+            n += value;
+            if n > MAX_ROMAN {
+                return Err(ParseRomanError);
+            }
+            rest = r;
+        }
+    }
+    if n == 0 || !rest.is_empty() {
+        return Err(ParseRomanError);
+    }
+    // reject non-canonical forms such as "IIII" by writing it back.
+    let mut rest = s.as_bytes();
+    for_each_symbol(n, false, |sym| {
+        rest = strip_symbol(rest, sym).ok_or(ParseRomanError)?;
+        Ok(())
+    })?;
+    Ok(n)
+}
+
+/// Display adapter, which formats the 1-based value in Roman numerals, such as "XIV".
+/// Values larger than 3999, which have no standard Roman numerals, are formatted in Arabic numerals.
+///
+/// Created by `roman()` and `roman_lower()` methods of `OneBased*` types.
+///
+/// ```
+/// # use one_based::OneBasedU16;
+/// let v = OneBasedU16::from_one_based(14)?;
+/// assert_eq!(format!("Chapter {}", v.roman()), "Chapter XIV");
+/// assert_eq!(format!("page {}", v.roman_lower()), "page xiv");
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Roman<I> {
+    value: I,
+    numeral: Option<u16>,
+    lower: bool,
+}
+
+impl<I> Roman<I> {
+    /// `one_based` must be the 1-based value of `value`.
+    pub(crate) const fn new(value: I, one_based: u128, lower: bool) -> Self {
+        let numeral = if one_based <= MAX_ROMAN as u128 {
+            Some(one_based as u16)
+        } else {
+            None
+        };
+        Self {
+            value,
+            numeral,
+            lower,
+        }
+    }
+}
+
+impl<I: OneBasedInteger> Display for Roman<I> {
+    /// Formats the numeral as a whole, respecting the width, fill and alignment.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write as _;
+
+        // the longest numeral "MMMDCCCLXXXVIII" is shorter than any `u128` digits.
+        let mut buf = [0u8; MAX_DIGITS];
+        let mut w = SliceWriter::new(&mut buf);
+        match self.numeral {
+            Some(n) => for_each_symbol(n, self.lower, |sym| w.write_str(sym))?,
+            None => write!(w, "{}", self.value)?,
+        }
+        f.pad(w.into_str())
+    }
+}
@@ -0,0 +1,480 @@
+//! Provides [`serde`](::serde) integration.
+//!
+//! Also provides modules to customize the representation of `OneBased*` types,
+//! to be used with `#[serde(with = "...")]` attribute.
+
+use core::num::{IntErrorKind, ParseIntError};
+
+use ::serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{OneBased, OneBasedError, UnsignedNonZero, ZERO_DESERIALIZE_MESSAGE};
+
+#[cfg(feature = "serde_with")]
+pub use self::serde_with::{AsString, AsZeroBased, ZeroAsNone};
+
+impl<T> Serialize for OneBased<T>
+where
+    T: UnsignedNonZero,
+    T::Int: Serialize,
+{
+    /// Serializes the 1-based value as the bare unsigned integer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.get().serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for OneBased<T>
+where
+    T: UnsignedNonZero,
+    T::Int: Deserialize<'de>,
+{
+    /// Deserializes the 1-based value from the bare unsigned integer, with the descriptive error for 0.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::new(T::Int::deserialize(deserializer)?)
+            .map(OneBased)
+            .ok_or_else(zero_error)
+    }
+}
+
+/// Returns the error for 0 passed as 1-based index, shared by all the representations.
+fn zero_error<E: Error>() -> E {
+    E::custom(ZERO_DESERIALIZE_MESSAGE)
+}
+
+/// Converts [`OneBasedError`] into the deserialization error.
+fn convert_error<E: Error>(e: OneBasedError) -> E {
+    match e {
+        OneBasedError::ZeroIndex => zero_error(),
+        e => E::custom(e),
+    }
+}
+
+/// Converts [`ParseIntError`] of `OneBased*` into the deserialization error.
+fn parse_error<E: Error>(e: ParseIntError) -> E {
+    match e.kind() {
+        IntErrorKind::Zero => zero_error(),
+        _ => E::custom(e),
+    }
+}
+
+/// Serializes `OneBased*` types as the 0-based number.
+///
+/// ```
+/// # use one_based::OneBasedU32;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Cursor {
+///     #[serde(with = "one_based::serde::zero_based")]
+///     row: OneBasedU32,
+///     #[serde(with = "one_based::serde::zero_based::option")]
+///     column: Option<OneBasedU32>,
+/// }
+///
+/// let cursor: Cursor = serde_json::from_str(r#"{"row": 0, "column": 4}"#).unwrap();
+/// assert_eq!(cursor.row, OneBasedU32::FIRST);
+/// assert_eq!(cursor.column, Some(OneBasedU32::from_one_based(5)?));
+/// assert_eq!(serde_json::to_string(&cursor).unwrap(), r#"{"row":0,"column":4}"#);
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+pub mod zero_based {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::OneBasedInteger;
+
+    /// Serializes the 0-based value.
+    pub fn serialize<I, S>(v: &I, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: OneBasedInteger,
+        I::Int: Serialize,
+        S: Serializer,
+    {
+        v.as_zero_based().serialize(serializer)
+    }
+
+    /// Deserializes the 0-based value.
+    pub fn deserialize<'de, I, D>(deserializer: D) -> Result<I, D::Error>
+    where
+        I: OneBasedInteger,
+        I::Int: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        I::from_zero_based(I::Int::deserialize(deserializer)?).map_err(super::convert_error)
+    }
+
+    /// Serializes `Option<OneBased*>` as the optional 0-based number.
+    pub mod option {
+        use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        use crate::OneBasedInteger;
+
+        /// Serializes the optional 0-based value.
+        pub fn serialize<I, S>(v: &Option<I>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            I: OneBasedInteger,
+            I::Int: Serialize,
+            S: Serializer,
+        {
+            v.map(|v| v.as_zero_based()).serialize(serializer)
+        }
+
+        /// Deserializes the optional 0-based value.
+        pub fn deserialize<'de, I, D>(deserializer: D) -> Result<Option<I>, D::Error>
+        where
+            I: OneBasedInteger,
+            I::Int: Deserialize<'de>,
+            D: Deserializer<'de>,
+        {
+            Option::<I::Int>::deserialize(deserializer)?
+                .map(I::from_zero_based)
+                .transpose()
+                .map_err(super::super::convert_error)
+        }
+    }
+}
+
+/// Serializes `OneBased*` types as the decimal string.
+///
+/// ```
+/// # use one_based::OneBasedU32;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Page {
+///     #[serde(with = "one_based::serde::string")]
+///     number: OneBasedU32,
+///     #[serde(with = "one_based::serde::string::option")]
+///     next: Option<OneBasedU32>,
+/// }
+///
+/// let page: Page = serde_json::from_str(r#"{"number": "3", "next": null}"#).unwrap();
+/// assert_eq!(page.number, OneBasedU32::from_one_based(3)?);
+/// assert_eq!(page.next, None);
+/// assert_eq!(serde_json::to_string(&page).unwrap(), r#"{"number":"3","next":null}"#);
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+pub mod string {
+    use core::{fmt::Formatter, marker::PhantomData};
+
+    use ::serde::{
+        de::{Error, Visitor},
+        Deserializer, Serializer,
+    };
+
+    use crate::OneBasedInteger;
+
+    /// Serializes the 1-based value as string.
+    pub fn serialize<I, S>(v: &I, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: OneBasedInteger,
+        S: Serializer,
+    {
+        serializer.collect_str(v)
+    }
+
+    /// Deserializes the 1-based value from string.
+    pub fn deserialize<'de, I, D>(deserializer: D) -> Result<I, D::Error>
+    where
+        I: OneBasedInteger,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrVisitor(PhantomData))
+    }
+
+    struct StrVisitor<I>(PhantomData<I>);
+
+    impl<I: OneBasedInteger> Visitor<'_> for StrVisitor<I> {
+        type Value = I;
+
+        fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+            formatter.write_str("1-based index as a decimal string")
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map_err(super::parse_error)
+        }
+    }
+
+    /// Serializes `Option<OneBased*>` as the optional decimal string.
+    pub mod option {
+        use core::{fmt::Formatter, marker::PhantomData};
+
+        use ::serde::{de::Visitor, Deserializer, Serialize, Serializer};
+
+        use crate::OneBasedInteger;
+
+        struct AsString<'a, I>(&'a I);
+
+        impl<I: OneBasedInteger> Serialize for AsString<'_, I> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                super::serialize(self.0, serializer)
+            }
+        }
+
+        /// Serializes the optional 1-based value as string.
+        pub fn serialize<I, S>(v: &Option<I>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            I: OneBasedInteger,
+            S: Serializer,
+        {
+            match v {
+                None => serializer.serialize_none(),
+                Some(v) => serializer.serialize_some(&AsString(v)),
+            }
+        }
+
+        /// Deserializes the optional 1-based value from string.
+        pub fn deserialize<'de, I, D>(deserializer: D) -> Result<Option<I>, D::Error>
+        where
+            I: OneBasedInteger,
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_option(OptionVisitor(PhantomData))
+        }
+
+        struct OptionVisitor<I>(PhantomData<I>);
+
+        impl<'de, I: OneBasedInteger> Visitor<'de> for OptionVisitor<I> {
+            type Value = Option<I>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+                formatter.write_str("optional 1-based index as a decimal string")
+            }
+
+            fn visit_none<E>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                super::deserialize(deserializer).map(Some)
+            }
+        }
+    }
+}
+
+/// Serializes `OneBased*` types as the 1-based number,
+/// and deserializes from either the number or the decimal string.
+///
+/// ```
+/// # use one_based::OneBasedU32;
+/// #[derive(serde::Deserialize)]
+/// struct Item {
+///     #[serde(with = "one_based::serde::flexible")]
+///     position: OneBasedU32,
+/// }
+///
+/// let item: Item = serde_json::from_str(r#"{"position": 3}"#).unwrap();
+/// assert_eq!(item.position, OneBasedU32::from_one_based(3)?);
+/// let item: Item = serde_json::from_str(r#"{"position": "3"}"#).unwrap();
+/// assert_eq!(item.position, OneBasedU32::from_one_based(3)?);
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+pub mod flexible {
+    use core::{convert::TryFrom, fmt::Formatter, marker::PhantomData};
+
+    use ::serde::{
+        de::{Error, Unexpected, Visitor},
+        Deserializer, Serialize, Serializer,
+    };
+
+    use crate::{OneBasedInteger, UnsignedNonZero as _};
+
+    /// Serializes the 1-based value as number.
+    pub fn serialize<I, S>(v: &I, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: OneBasedInteger,
+        I::Int: Serialize,
+        S: Serializer,
+    {
+        v.as_one_based().get().serialize(serializer)
+    }
+
+    /// Deserializes the 1-based value from number or string.
+    pub fn deserialize<'de, I, D>(deserializer: D) -> Result<I, D::Error>
+    where
+        I: OneBasedInteger,
+        I::Int: TryFrom<u128>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(FlexibleVisitor(PhantomData))
+    }
+
+    struct FlexibleVisitor<I>(PhantomData<I>);
+
+    impl<I> Visitor<'_> for FlexibleVisitor<I>
+    where
+        I: OneBasedInteger,
+        I::Int: TryFrom<u128>,
+    {
+        type Value = I;
+
+        fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+            formatter.write_str("1-based index as a number or a decimal string")
+        }
+
+        fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+            self.visit_u128(v.into())
+        }
+
+        fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+            let v = I::Int::try_from(v)
+                .map_err(|_| E::invalid_value(Unexpected::Other("too large number"), &self))?;
+            I::from_one_based(v).map_err(super::convert_error)
+        }
+
+        fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+            self.visit_i128(v.into())
+        }
+
+        fn visit_i128<E: Error>(self, v: i128) -> Result<Self::Value, E> {
+            let v = u128::try_from(v)
+                .map_err(|_| E::invalid_value(Unexpected::Other("negative number"), &self))?;
+            self.visit_u128(v)
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map_err(super::parse_error)
+        }
+    }
+}
+
+/// Serializes `Option<OneBased*>` as the 1-based number, where `None` is represented as 0.
+///
+/// ```
+/// # use one_based::OneBasedU32;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Node {
+///     #[serde(with = "one_based::serde::zero_as_none")]
+///     parent: Option<OneBasedU32>,
+/// }
+///
+/// let node: Node = serde_json::from_str(r#"{"parent": 0}"#).unwrap();
+/// assert_eq!(node.parent, None);
+/// let node: Node = serde_json::from_str(r#"{"parent": 2}"#).unwrap();
+/// assert_eq!(node.parent, Some(OneBasedU32::from_one_based(2)?));
+/// assert_eq!(serde_json::to_string(&Node { parent: None }).unwrap(), r#"{"parent":0}"#);
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+pub mod zero_as_none {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{OneBasedInteger, UnsignedNonZero as _};
+
+    /// Serializes the optional 1-based value, or 0 for `None`.
+    pub fn serialize<I, S>(v: &Option<I>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: OneBasedInteger,
+        I::Int: Serialize,
+        S: Serializer,
+    {
+        match v {
+            Some(v) => v.as_one_based().get(),
+            // 0-based value of FIRST is zero.
+            None => I::FIRST.as_zero_based(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes the optional 1-based value, where 0 means `None`.
+    pub fn deserialize<'de, I, D>(deserializer: D) -> Result<Option<I>, D::Error>
+    where
+        I: OneBasedInteger,
+        I::Int: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Ok(I::from_one_based_opt(I::Int::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(feature = "serde_with")]
+mod serde_with {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use ::serde_with::{DeserializeAs, SerializeAs};
+
+    use crate::OneBasedInteger;
+
+    /// [`serde_with`](::serde_with) adapter equivalent to [`zero_based`](super::zero_based) module.
+    ///
+    /// ```
+    /// # use one_based::{serde::AsZeroBased, OneBasedU32};
+    /// #[serde_with::serde_as]
+    /// #[derive(serde::Serialize, serde::Deserialize)]
+    /// struct Selection {
+    ///     #[serde_as(as = "Vec<AsZeroBased>")]
+    ///     rows: Vec<OneBasedU32>,
+    /// }
+    ///
+    /// let selection: Selection = serde_json::from_str(r#"{"rows": [0, 2]}"#).unwrap();
+    /// assert_eq!(selection.rows, [OneBasedU32::FIRST, OneBasedU32::from_one_based(3)?]);
+    /// # Ok::<(), one_based::OneBasedError>(())
+    /// ```
+    #[derive(Debug, Clone, Copy)]
+    pub struct AsZeroBased;
+
+    impl<I> SerializeAs<I> for AsZeroBased
+    where
+        I: OneBasedInteger,
+        I::Int: Serialize,
+    {
+        fn serialize_as<S: Serializer>(source: &I, serializer: S) -> Result<S::Ok, S::Error> {
+            super::zero_based::serialize(source, serializer)
+        }
+    }
+
+    impl<'de, I> DeserializeAs<'de, I> for AsZeroBased
+    where
+        I: OneBasedInteger,
+        I::Int: Deserialize<'de>,
+    {
+        fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<I, D::Error> {
+            super::zero_based::deserialize(deserializer)
+        }
+    }
+
+    /// [`serde_with`](::serde_with) adapter equivalent to [`string`](super::string) module.
+    #[derive(Debug, Clone, Copy)]
+    pub struct AsString;
+
+    impl<I: OneBasedInteger> SerializeAs<I> for AsString {
+        fn serialize_as<S: Serializer>(source: &I, serializer: S) -> Result<S::Ok, S::Error> {
+            super::string::serialize(source, serializer)
+        }
+    }
+
+    impl<'de, I: OneBasedInteger> DeserializeAs<'de, I> for AsString {
+        fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<I, D::Error> {
+            super::string::deserialize(deserializer)
+        }
+    }
+
+    /// [`serde_with`](::serde_with) adapter equivalent to [`zero_as_none`](super::zero_as_none) module,
+    /// which is applied to `Option<OneBased*>`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct ZeroAsNone;
+
+    impl<I> SerializeAs<Option<I>> for ZeroAsNone
+    where
+        I: OneBasedInteger,
+        I::Int: Serialize,
+    {
+        fn serialize_as<S: Serializer>(
+            source: &Option<I>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            super::zero_as_none::serialize(source, serializer)
+        }
+    }
+
+    impl<'de, I> DeserializeAs<'de, Option<I>> for ZeroAsNone
+    where
+        I: OneBasedInteger,
+        I::Int: Deserialize<'de>,
+    {
+        fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Option<I>, D::Error> {
+            super::zero_as_none::deserialize(deserializer)
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! Provides extension of slices indexed by `OneBased*` types.
+
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+
+use crate::{sealed, OneBasedRange, OneBasedUsize};
+
+/// Extension trait of slices to access elements with 1-based indices.
+///
+/// ```
+/// # use one_based::{OneBasedSliceExt, OneBasedUsize};
+/// let mut items = ["apple", "banana", "cherry"];
+/// let second = OneBasedUsize::from_one_based(2)?;
+/// assert_eq!(items.get_one_based(second), Some(&"banana"));
+/// assert_eq!(*items.one_based(second), "banana");
+///
+/// *items.one_based_mut(OneBasedUsize::FIRST) = "apricot";
+/// assert_eq!(items, ["apricot", "banana", "cherry"]);
+/// assert_eq!(items.get_one_based(OneBasedUsize::from_one_based(4)?), None);
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+///
+/// Ranges are also supported with [`OneBasedRange`].
+///
+/// ```
+/// # use one_based::{OneBasedRange, OneBasedSliceExt, OneBasedUsize};
+/// let lines: Vec<String> = (1..=30).map(|i| format!("line {}", i)).collect();
+/// let selected: OneBasedRange<OneBasedUsize> = "10-20".parse().unwrap();
+/// let selected = lines.one_based_range(selected);
+/// assert_eq!(selected.len(), 11);
+/// assert_eq!(selected[0], "line 10");
+/// assert_eq!(selected[10], "line 20");
+///
+/// assert_eq!(lines.get_one_based_range("25-31".parse().unwrap()), None);
+/// ```
+pub trait OneBasedSliceExt: sealed::Sealed {
+    /// Element type of the slice.
+    type Item;
+
+    /// Returns the element at the given 1-based index,
+    /// or `None` if the index is out of bounds.
+    fn get_one_based(&self, index: OneBasedUsize) -> Option<&Self::Item>;
+
+    /// Returns the mutable element at the given 1-based index,
+    /// or `None` if the index is out of bounds.
+    fn get_one_based_mut(&mut self, index: OneBasedUsize) -> Option<&mut Self::Item>;
+
+    /// Returns the element at the given 1-based index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn one_based(&self, index: OneBasedUsize) -> &Self::Item;
+
+    /// Returns the mutable element at the given 1-based index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn one_based_mut(&mut self, index: OneBasedUsize) -> &mut Self::Item;
+
+    /// Returns the subslice of the given 1-based range,
+    /// or `None` if the range is out of bounds.
+    fn get_one_based_range(&self, range: OneBasedRange<OneBasedUsize>) -> Option<&[Self::Item]>;
+
+    /// Returns the mutable subslice of the given 1-based range,
+    /// or `None` if the range is out of bounds.
+    fn get_one_based_range_mut(
+        &mut self,
+        range: OneBasedRange<OneBasedUsize>,
+    ) -> Option<&mut [Self::Item]>;
+
+    /// Returns the subslice of the given 1-based range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    fn one_based_range(&self, range: OneBasedRange<OneBasedUsize>) -> &[Self::Item];
+
+    /// Returns the mutable subslice of the given 1-based range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    fn one_based_range_mut(&mut self, range: OneBasedRange<OneBasedUsize>) -> &mut [Self::Item];
+
+    /// Returns the view of the slice, which is indexed by 1-based indices with `[]` syntax.
+    fn one_based_view(&self) -> IndexOneBased<&[Self::Item]>;
+
+    /// Returns the mutable view of the slice, which is indexed by 1-based indices with `[]` syntax.
+    fn one_based_view_mut(&mut self) -> IndexOneBased<&mut [Self::Item]>;
+}
+
+impl<T> sealed::Sealed for [T] {}
+
+/// Panics on the 1-based index out of bounds.
+#[cold]
+#[track_caller]
+fn index_out_of_bounds(index: OneBasedUsize, len: usize) -> ! {
+    panic!(
+        "1-based index {} (0-based {}) is out of bounds for length {}",
+        index,
+        index.as_zero_based(),
+        len
+    )
+}
+
+/// Panics on the 1-based range out of bounds.
+#[cold]
+#[track_caller]
+fn range_out_of_bounds(range: &OneBasedRange<OneBasedUsize>, len: usize) -> ! {
+    panic!(
+        "1-based range {} (0-based {:?}) is out of bounds for length {}",
+        range,
+        range.as_zero_based(),
+        len
+    )
+}
+
+impl<T> OneBasedSliceExt for [T] {
+    type Item = T;
+
+    #[inline]
+    fn get_one_based(&self, index: OneBasedUsize) -> Option<&T> {
+        self.get(index.as_zero_based())
+    }
+
+    #[inline]
+    fn get_one_based_mut(&mut self, index: OneBasedUsize) -> Option<&mut T> {
+        self.get_mut(index.as_zero_based())
+    }
+
+    #[inline]
+    #[track_caller]
+    fn one_based(&self, index: OneBasedUsize) -> &T {
+        match self.get(index.as_zero_based()) {
+            Some(v) => v,
+            None => index_out_of_bounds(index, self.len()),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn one_based_mut(&mut self, index: OneBasedUsize) -> &mut T {
+        let len = self.len();
+        match self.get_mut(index.as_zero_based()) {
+            Some(v) => v,
+            None => index_out_of_bounds(index, len),
+        }
+    }
+
+    #[inline]
+    fn get_one_based_range(&self, range: OneBasedRange<OneBasedUsize>) -> Option<&[T]> {
+        self.get(range.as_zero_based())
+    }
+
+    #[inline]
+    fn get_one_based_range_mut(&mut self, range: OneBasedRange<OneBasedUsize>) -> Option<&mut [T]> {
+        self.get_mut(range.as_zero_based())
+    }
+
+    #[inline]
+    #[track_caller]
+    fn one_based_range(&self, range: OneBasedRange<OneBasedUsize>) -> &[T] {
+        match self.get(range.as_zero_based()) {
+            Some(v) => v,
+            None => range_out_of_bounds(&range, self.len()),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn one_based_range_mut(&mut self, range: OneBasedRange<OneBasedUsize>) -> &mut [T] {
+        let len = self.len();
+        match self.get_mut(range.as_zero_based()) {
+            Some(v) => v,
+            None => range_out_of_bounds(&range, len),
+        }
+    }
+
+    #[inline]
+    fn one_based_view(&self) -> IndexOneBased<&[T]> {
+        IndexOneBased::new(self)
+    }
+
+    #[inline]
+    fn one_based_view_mut(&mut self) -> IndexOneBased<&mut [T]> {
+        IndexOneBased::new(self)
+    }
+}
+
+/// Wrapper of slice or `Vec`, which implements [`Index`] and [`IndexMut`]
+/// with [`OneBasedUsize`] and [`OneBasedRange`].
+///
+/// This is created by [`OneBasedSliceExt::one_based_view`], or [`IndexOneBased::new`] to own the collection.
+///
+/// ```
+/// # use one_based::{IndexOneBased, OneBasedSliceExt, OneBasedUsize};
+/// let first = OneBasedUsize::FIRST;
+/// let second = OneBasedUsize::from_one_based(2)?;
+/// let third = OneBasedUsize::from_one_based(3)?;
+///
+/// let items = ["apple", "banana", "cherry"];
+/// let view = items.one_based_view();
+/// assert_eq!(view[second], "banana");
+/// assert_eq!(view[second.iter_to(third)], ["banana", "cherry"]);
+///
+/// let mut rows = IndexOneBased::new(vec![0; 3]);
+/// rows[first] = 1;
+/// rows[second] += 2;
+/// assert_eq!(rows.into_inner(), [1, 2, 0]);
+/// # Ok::<(), one_based::OneBasedError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(transparent)]
+pub struct IndexOneBased<S>(S);
+
+impl<S> IndexOneBased<S> {
+    /// Wraps the collection to index with 1-based indices.
+    #[inline]
+    pub const fn new(inner: S) -> Self {
+        Self(inner)
+    }
+
+    /// Unwraps the collection.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<S> Index<OneBasedUsize> for IndexOneBased<S>
+where
+    S: Deref,
+    S::Target: OneBasedSliceExt,
+{
+    type Output = <S::Target as OneBasedSliceExt>::Item;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: OneBasedUsize) -> &Self::Output {
+        self.0.one_based(index)
+    }
+}
+
+impl<S> IndexMut<OneBasedUsize> for IndexOneBased<S>
+where
+    S: DerefMut,
+    S::Target: OneBasedSliceExt,
+{
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: OneBasedUsize) -> &mut Self::Output {
+        self.0.one_based_mut(index)
+    }
+}
+
+impl<S> Index<OneBasedRange<OneBasedUsize>> for IndexOneBased<S>
+where
+    S: Deref,
+    S::Target: OneBasedSliceExt,
+{
+    type Output = [<S::Target as OneBasedSliceExt>::Item];
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, range: OneBasedRange<OneBasedUsize>) -> &Self::Output {
+        self.0.one_based_range(range)
+    }
+}
+
+impl<S> IndexMut<OneBasedRange<OneBasedUsize>> for IndexOneBased<S>
+where
+    S: DerefMut,
+    S::Target: OneBasedSliceExt,
+{
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, range: OneBasedRange<OneBasedUsize>) -> &mut Self::Output {
+        self.0.one_based_range_mut(range)
+    }
+}
@@ -0,0 +1,79 @@
+//! Provides [`sqlx`] integration.
+//!
+//! As Postgres has no unsigned integer types, `OneBased*` types are stored as
+//! signed integer of the width which can hold all the 1-based values:
+//!
+//! | Type | Rust | SQL |
+//! |------|------|-----|
+//! | `OneBasedU8` | `i16` | `SMALLINT` |
+//! | `OneBasedU16` | `i32` | `INTEGER` |
+//! | `OneBasedU32` | `i64` | `BIGINT` |
+//! | `OneBasedU64` | `i64` | `BIGINT` |
+//! | `OneBasedUsize` | `i64` | `BIGINT` |
+//!
+//! Encoding `OneBasedU64` or `OneBasedUsize` fails if the value doesn't fit in `i64`,
+//! and decoding fails on zero, negative or too large values.
+
+use ::sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+use crate::{OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize};
+
+macro_rules! impl_sqlx {
+    (@common $name:ident, $stype:ty) => {
+        impl<DB: Database> Type<DB> for $name
+        where
+            $stype: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <$stype as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$stype as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'r, DB: Database> Decode<'r, DB> for $name
+        where
+            $stype: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                let v = <$stype as Decode<'r, DB>>::decode(value)?;
+                Ok(<$name>::try_from_db_i64(v.into())?)
+            }
+        }
+    };
+    (lossless: $($name:ident, $stype:ty);+) => {$(
+        impl_sqlx!(@common $name, $stype);
+
+        impl<'q, DB: Database> Encode<'q, DB> for $name
+        where
+            $stype: Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+            ) -> Result<IsNull, BoxDynError> {
+                <$stype>::from(self.as_one_based().get()).encode_by_ref(buf)
+            }
+        }
+    )*};
+    (fallible: $($name:ident);+) => {$(
+        impl_sqlx!(@common $name, i64);
+
+        impl<'q, DB: Database> Encode<'q, DB> for $name
+        where
+            i64: Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+            ) -> Result<IsNull, BoxDynError> {
+                self.try_as_db_i64()?.encode_by_ref(buf)
+            }
+        }
+    )*};
+}
+
+impl_sqlx!(lossless: OneBasedU8, i16; OneBasedU16, i32; OneBasedU32, i64);
+impl_sqlx!(fallible: OneBasedU64; OneBasedUsize);
@@ -0,0 +1,54 @@
+//! Provides [`rusqlite`] integration.
+//!
+//! `OneBased*` types are stored as SQLite `INTEGER` holding the 1-based value.
+//! Reading zero, negative or too large integer fails with [`FromSqlError::OutOfRange`].
+//!
+//! ```
+//! # use one_based::OneBasedU32;
+//! let conn = rusqlite::Connection::open_in_memory()?;
+//! let v = OneBasedU32::from_one_based(3).unwrap();
+//! let got: OneBasedU32 = conn.query_row("SELECT ?1", [v], |row| row.get(0))?;
+//! assert_eq!(got, v);
+//!
+//! let zero = conn.query_row("SELECT 0", [], |row| row.get::<_, OneBasedU32>(0));
+//! assert!(zero.is_err());
+//! # Ok::<(), rusqlite::Error>(())
+//! ```
+
+use ::rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::{OneBasedU128, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize};
+
+macro_rules! impl_rusqlite {
+    (@common $name:ident) => {
+        impl FromSql for $name {
+            fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+                let v = i64::column_result(value)?;
+                <$name>::try_from_db_i64(v).map_err(|_| FromSqlError::OutOfRange(v))
+            }
+        }
+    };
+    (lossless: $($name:ident),+) => {$(
+        impl_rusqlite!(@common $name);
+
+        impl ToSql for $name {
+            fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+                Ok(self.as_db_i64().into())
+            }
+        }
+    )*};
+    (fallible: $($name:ident),+) => {$(
+        impl_rusqlite!(@common $name);
+
+        impl ToSql for $name {
+            fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+                self.try_as_db_i64()
+                    .map(Into::into)
+                    .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))
+            }
+        }
+    )*};
+}
+
+impl_rusqlite!(lossless: OneBasedU8, OneBasedU16, OneBasedU32);
+impl_rusqlite!(fallible: OneBasedU64, OneBasedU128, OneBasedUsize);
@@ -0,0 +1,325 @@
+//! Provides OneBasedStoredZero* types, which store 0-based index internally.
+
+use core::{
+    cmp::Ordering,
+    fmt::Display,
+    num::{
+        NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, ParseIntError,
+    },
+    str::FromStr,
+};
+
+use crate::{
+    OneBasedError, OneBasedU128, OneBasedU16, OneBasedU32, OneBasedU64, OneBasedU8, OneBasedUsize,
+};
+
+macro_rules! define_one_based_stored_zero {
+    ($name:ident, $onebased:ident, $onebased_str:literal, $itype:ty, $nonzerotype:ty) => {
+        #[doc = concat!(r" Represents 1-based index of ", stringify!($itype), r", stored as 0-based index.")]
+        ///
+        #[doc = concat!(r" This type has the same API surface as [`", stringify!($onebased), r"`],")]
+        /// but stores the 0-based value as bitwise complement in a NonZero,
+        /// so that `MAX` 0-based index is the niche instead of 0.
+        /// `as_zero_based()` is then a bitwise NOT instead of the subtraction, which is
+        /// not necessarily cheaper, while `Option<$name>` is still the same size as the plain integer.
+        ///
+        /// ```
+        #[doc = concat!(r" # use one_based::", stringify!($name), r";")]
+        #[doc = concat!(r" let v = ", stringify!($name),r"::from_one_based(5)?;")]
+        #[doc = r" assert_eq!(v.as_zero_based(), 4);"]
+        #[doc = r#" assert_eq!(v.to_string(), "5");"#]
+        #[doc = r" # Ok::<(), one_based::OneBasedError>(())"]
+        /// ```
+        #[derive(PartialEq, Eq, Hash, Clone, Copy)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(from = $onebased_str, into = $onebased_str)
+        )]
+        pub struct $name($nonzerotype);
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.as_one_based())
+                    .finish()
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.as_zero_based().cmp(&other.as_zero_based())
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.as_one_based().fmt(f)
+            }
+        }
+
+        #[cfg(feature = "bincode")]
+        impl bincode::Encode for $name {
+            /// Encodes the 1-based value.
+            fn encode<E: bincode::enc::Encoder>(
+                &self,
+                encoder: &mut E,
+            ) -> Result<(), bincode::error::EncodeError> {
+                bincode::Encode::encode(&<$onebased>::from(*self), encoder)
+            }
+        }
+
+        #[cfg(feature = "bincode")]
+        impl<Context> bincode::Decode<Context> for $name {
+            /// Decodes the 1-based value.
+            fn decode<D: bincode::de::Decoder<Context = Context>>(
+                decoder: &mut D,
+            ) -> Result<Self, bincode::error::DecodeError> {
+                <$onebased as bincode::Decode<Context>>::decode(decoder).map(Self::from)
+            }
+        }
+
+        #[cfg(feature = "bincode")]
+        bincode::impl_borrow_decode!($name);
+
+        #[cfg(feature = "borsh")]
+        impl borsh::BorshSerialize for $name {
+            /// Serializes the 1-based value.
+            fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+                borsh::BorshSerialize::serialize(&<$onebased>::from(*self), writer)
+            }
+        }
+
+        #[cfg(feature = "borsh")]
+        impl borsh::BorshDeserialize for $name {
+            /// Deserializes the 1-based value.
+            fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+                <$onebased as borsh::BorshDeserialize>::deserialize_reader(reader).map(Self::from)
+            }
+        }
+
+        #[cfg(feature = "musli")]
+        impl<M> musli::Encode<M> for $name
+        where
+            $itype: musli::Encode<M>,
+        {
+            type Encode = Self;
+
+            /// Encodes the 1-based value.
+            fn encode<E: musli::Encoder<Mode = M>>(&self, encoder: E) -> Result<(), E::Error> {
+                musli::Encode::encode(&<$onebased>::from(*self), encoder)
+            }
+
+            fn as_encode(&self) -> &Self::Encode {
+                self
+            }
+        }
+
+        #[cfg(feature = "musli")]
+        impl<'de, M, A> musli::Decode<'de, M, A> for $name
+        where
+            A: musli::Allocator,
+            $itype: musli::Decode<'de, M, A>,
+        {
+            /// Decodes the 1-based value.
+            fn decode<D: musli::Decoder<'de, Mode = M, Allocator = A>>(
+                decoder: D,
+            ) -> Result<Self, D::Error> {
+                <$onebased as musli::Decode<'de, M, A>>::decode(decoder).map(Self::from)
+            }
+        }
+
+        #[cfg(feature = "postcard")]
+        impl postcard::experimental::max_size::MaxSize for $name {
+            const POSTCARD_MAX_SIZE: usize =
+                <$onebased as postcard::experimental::max_size::MaxSize>::POSTCARD_MAX_SIZE;
+        }
+
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for $name {
+            /// Emits the 1-based value.
+            fn format(&self, f: defmt::Formatter<'_>) {
+                self.as_one_based().format(f)
+            }
+        }
+
+        #[cfg(feature = "ufmt")]
+        impl ufmt::uDisplay for $name {
+            /// Writes the 1-based value.
+            fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+                ufmt::uDisplay::fmt(&self.as_one_based().get(), f)
+            }
+        }
+
+        #[cfg(feature = "ufmt")]
+        impl ufmt::uDebug for $name {
+            /// Writes the 1-based value.
+            fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+                ufmt::uDebug::fmt(&self.as_one_based().get(), f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let v: $onebased = s.parse()?;
+                Ok(v.into())
+            }
+        }
+
+        impl core::convert::From<$onebased> for $name {
+            #[doc = concat!(r"Converts [`", stringify!($onebased), r"`] to [`", stringify!($name), r"`].")]
+            #[inline]
+            fn from(value: $onebased) -> Self {
+                // 0-based index of $onebased is never MAX.
+                unsafe { Self::from_zero_based_unchecked(value.as_zero_based()) }
+            }
+        }
+
+        impl core::convert::From<$name> for $onebased {
+            #[doc = concat!(r"Converts [`", stringify!($name), r"`] to [`", stringify!($onebased), r"`].")]
+            #[inline]
+            fn from(value: $name) -> Self {
+                <$onebased>::from_one_based_nonzero(value.as_one_based())
+            }
+        }
+
+        impl $name {
+            /// Creates `$name` from 1-based index value.
+            /// Returns error if the given index is zero.
+            #[inline]
+            pub const fn from_one_based(v: $itype) -> Result<Self, OneBasedError> {
+                if v == 0 {
+                    return Err(OneBasedError::ZeroIndex);
+                }
+                // this won't overflow, and cannot be MAX.
+                Ok(unsafe { Self::from_zero_based_unchecked(v - 1) })
+            }
+
+            /// Creates `$name` from 1-based index value.
+            /// Returns `None` if the given index is zero.
+            #[inline]
+            pub const fn from_one_based_opt(v: $itype) -> Option<Self> {
+                match Self::from_one_based(v) {
+                    Ok(v) => Some(v),
+                    Err(_) => None,
+                }
+            }
+
+            /// Creates `$name` from 1-based index value without check.
+            ///
+            /// # Safety
+            ///
+            /// Input must be greater than zero.
+            #[inline]
+            pub const unsafe fn from_one_based_unchecked(v: $itype) -> Self {
+                // this cannot be MAX for non-zero input.
+                unsafe { Self::from_zero_based_unchecked(v - 1) }
+            }
+
+            /// Creates `$name` from 1-based index value as [`$nonzerotype`].
+            /// This will always succeed.
+            #[inline]
+            pub const fn from_one_based_nonzero(v: $nonzerotype) -> Self {
+                // this won't overflow, and cannot be MAX.
+                unsafe { Self::from_zero_based_unchecked(v.get() - 1) }
+            }
+
+            /// Creates `$name` from 0-based index value.
+            /// Returns error if the given index is MAX value,
+            /// as that would case overflow when converted to 1-based.
+            #[inline]
+            pub const fn from_zero_based(v: $itype) -> Result<Self, OneBasedError> {
+                match <$nonzerotype>::new(!v) {
+                    None => Err(OneBasedError::OverflowIndex {
+                        value: v as u128,
+                        bits: <$itype>::BITS,
+                    }),
+                    Some(v) => Ok($name(v)),
+                }
+            }
+
+            /// Creates `$name` from 0-based index value.
+            /// Returns `None` if the given index is MAX value.
+            #[inline]
+            pub const fn from_zero_based_opt(v: $itype) -> Option<Self> {
+                match <$nonzerotype>::new(!v) {
+                    None => None,
+                    Some(v) => Some($name(v)),
+                }
+            }
+
+            /// Creates `$name` from 0-based index value without check.
+            ///
+            /// # Safety
+            #[doc = concat!(r" This function results in undefined behavior when `v == ", stringify!($itype), r"::MAX`.")]
+            #[inline]
+            pub const unsafe fn from_zero_based_unchecked(v: $itype) -> Self {
+                $name(unsafe { <$nonzerotype>::new_unchecked(!v) })
+            }
+
+            /// Returns regular 0-based index.
+            #[inline]
+            pub const fn as_zero_based(&self) -> $itype {
+                !self.0.get()
+            }
+
+            /// Returns 1-based index.
+            #[inline]
+            pub const fn as_one_based(&self) -> $nonzerotype {
+                // this won't overflow, and cannot be zero.
+                unsafe { <$nonzerotype>::new_unchecked(self.as_zero_based() + 1) }
+            }
+        }
+    };
+}
+
+define_one_based_stored_zero!(
+    OneBasedStoredZeroU8,
+    OneBasedU8,
+    "OneBasedU8",
+    u8,
+    NonZeroU8
+);
+define_one_based_stored_zero!(
+    OneBasedStoredZeroU16,
+    OneBasedU16,
+    "OneBasedU16",
+    u16,
+    NonZeroU16
+);
+define_one_based_stored_zero!(
+    OneBasedStoredZeroU32,
+    OneBasedU32,
+    "OneBasedU32",
+    u32,
+    NonZeroU32
+);
+define_one_based_stored_zero!(
+    OneBasedStoredZeroU64,
+    OneBasedU64,
+    "OneBasedU64",
+    u64,
+    NonZeroU64
+);
+define_one_based_stored_zero!(
+    OneBasedStoredZeroU128,
+    OneBasedU128,
+    "OneBasedU128",
+    u128,
+    NonZeroU128
+);
+define_one_based_stored_zero!(
+    OneBasedStoredZeroUsize,
+    OneBasedUsize,
+    "OneBasedUsize",
+    usize,
+    NonZeroUsize
+);
@@ -0,0 +1,76 @@
+//! Provides LEB128 variable-length encoding, as used in Protocol Buffers.
+
+use core::fmt::Display;
+
+/// Error type used when encoding or decoding LEB128 varint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarintError {
+    /// The input ended before the last byte of the varint.
+    Truncated,
+    /// The decoded value doesn't fit in the target type.
+    Overflow,
+    /// The decoded value is 0, which is not a valid 1-based index.
+    Zero,
+    /// The output buffer is too small to hold the encoded varint.
+    BufferTooSmall,
+    /// The varint has redundant trailing zero groups, i.e. non-canonical encoding.
+    Overlong,
+}
+
+impl Display for VarintError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            VarintError::Truncated => "varint is truncated",
+            VarintError::Overflow => "varint is too large for the target type",
+            VarintError::Zero => "invalid varint 0: 1-based index must be >= 1",
+            VarintError::BufferTooSmall => "buffer is too small to encode varint",
+            VarintError::Overlong => "varint has non-canonical overlong encoding",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VarintError {}
+
+/// Maximum length of LEB128 varint of `u128`.
+#[cfg(feature = "std")]
+pub(crate) const MAX_LEN: usize = 19;
+
+/// Encodes `v` into `buf`, and returns the number of written bytes.
+pub(crate) fn encode(mut v: u128, buf: &mut [u8]) -> Result<usize, VarintError> {
+    for (i, b) in buf.iter_mut().enumerate() {
+        if v < 0x80 {
+            *b = v as u8;
+            return Ok(i + 1);
+        }
+        *b = (v as u8 & 0x7f) | 0x80;
+        v >>= 7;
+    }
+    Err(VarintError::BufferTooSmall)
+}
+
+/// Decodes varint of at most `bits` bits from `bytes`,
+/// and returns the value with the number of read bytes.
+/// Rejects overlong encodings, so that each value has exactly one representation.
+pub(crate) fn decode(bytes: &[u8], bits: u32) -> Result<(u128, usize), VarintError> {
+    let mut v: u128 = 0;
+    for (i, &b) in bytes.iter().enumerate() {
+        let shift = 7 * i as u32;
+        if shift >= bits {
+            return Err(VarintError::Overflow);
+        }
+        let part = (b & 0x7f) as u128;
+        if shift + 7 > bits && part >> (bits - shift) != 0 {
+            return Err(VarintError::Overflow);
+        }
+        v |= part << shift;
+        if b & 0x80 == 0 {
+            if b == 0 && i > 0 {
+                return Err(VarintError::Overlong);
+            }
+            return Ok((v, i + 1));
+        }
+    }
+    Err(VarintError::Truncated)
+}
@@ -0,0 +1,139 @@
+//! Provides [`wasm_bindgen`] and [`tsify`] integration.
+//!
+//! `OneBased*` types are converted to JavaScript numbers holding the 1-based value,
+//! and converted back only from integral numbers within the range of the type.
+//! TypeScript declarations are emitted as branded number types,
+//! such as `type OneBasedU32 = number & { readonly __oneBased: "OneBasedU32" }`,
+//! so that plain numbers are not passed as 1-based index by accident.
+//!
+//! ```no_run
+//! # use one_based::{FromJsValueError, OneBasedU32};
+//! use std::convert::TryFrom;
+//! use wasm_bindgen::prelude::*;
+//!
+//! #[wasm_bindgen]
+//! pub fn next_line(line: JsValue) -> Result<JsValue, FromJsValueError> {
+//!     let line = OneBasedU32::try_from(line)?;
+//!     Ok(OneBasedU32::from_zero_based(line.as_one_based().get()).unwrap().into())
+//! }
+//! ```
+
+use core::{convert::TryFrom, fmt::Display};
+
+use ::tsify::Tsify;
+use ::wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+use crate::{OneBasedError, OneBasedU16, OneBasedU32, OneBasedU8};
+
+/// Error type used when converting [`JsValue`] into `OneBased*` types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromJsValueError {
+    /// The value is not an integral number.
+    NotInteger,
+    /// The integer is not a valid 1-based index of the type.
+    InvalidIndex(OneBasedError),
+}
+
+impl Display for FromJsValueError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FromJsValueError::NotInteger => f.write_str("JavaScript value is not an integer"),
+            FromJsValueError::InvalidIndex(e) => write!(f, "invalid JavaScript index: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for FromJsValueError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FromJsValueError::NotInteger => None,
+            FromJsValueError::InvalidIndex(e) => Some(e),
+        }
+    }
+}
+
+impl From<OneBasedError> for FromJsValueError {
+    fn from(e: OneBasedError) -> Self {
+        FromJsValueError::InvalidIndex(e)
+    }
+}
+
+impl From<FromJsValueError> for JsValue {
+    /// Converts into JavaScript `RangeError` or `TypeError`, so that it can be thrown.
+    fn from(e: FromJsValueError) -> Self {
+        let message = e.to_string();
+        match e {
+            FromJsValueError::NotInteger => ::js_sys::TypeError::new(&message).into(),
+            FromJsValueError::InvalidIndex(_) => ::js_sys::RangeError::new(&message).into(),
+        }
+    }
+}
+
+/// Returns the 1-based index of the integral number.
+fn checked_index(v: f64, max: u32) -> Result<u32, FromJsValueError> {
+    if !v.is_finite() || v.fract() != 0.0 {
+        Err(FromJsValueError::NotInteger)
+    } else if v < 0.0 {
+        Err(OneBasedError::NegativeIndex { value: v as i128 }.into())
+    } else if v == 0.0 {
+        Err(OneBasedError::ZeroIndex.into())
+    } else if v > max as f64 {
+        Err(OneBasedError::OutOfRangeIndex {
+            value: v as u128,
+            bits: max.count_ones(),
+        }
+        .into())
+    } else {
+        Ok(v as u32)
+    }
+}
+
+macro_rules! impl_wasm {
+    ($($name:ident, $itype:ty);+) => {$(
+        impl From<$name> for JsValue {
+            #[doc = concat!(r"Converts [`", stringify!($name), r"`] to 1-based JavaScript number.")]
+            fn from(value: $name) -> Self {
+                JsValue::from(value.as_one_based().get())
+            }
+        }
+
+        impl TryFrom<JsValue> for $name {
+            type Error = FromJsValueError;
+
+            fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+                Self::try_from(&value)
+            }
+        }
+
+        impl TryFrom<&JsValue> for $name {
+            type Error = FromJsValueError;
+
+            fn try_from(value: &JsValue) -> Result<Self, Self::Error> {
+                let v = value.as_f64().ok_or(FromJsValueError::NotInteger)?;
+                let v = checked_index(v, <$itype>::MAX.into())?;
+                Ok(<$name>::from_one_based(v as $itype)?)
+            }
+        }
+
+        impl Tsify for $name {
+            type JsType = JsValue;
+
+            const DECL: &'static str = concat!(
+                "export type ",
+                stringify!($name),
+                " = number & { readonly __oneBased: \"",
+                stringify!($name),
+                "\" };"
+            );
+        }
+    )*};
+}
+
+impl_wasm!(OneBasedU8, u8; OneBasedU16, u16; OneBasedU32, u32);
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_DECL: &'static str = r#"
+export type OneBasedU8 = number & { readonly __oneBased: "OneBasedU8" };
+export type OneBasedU16 = number & { readonly __oneBased: "OneBasedU16" };
+export type OneBasedU32 = number & { readonly __oneBased: "OneBasedU32" };
+"#;
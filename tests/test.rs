@@ -1,5 +1,9 @@
 #![no_std]
 
+// `validator` derive refers to `::std` paths.
+#[cfg(feature = "validator")]
+extern crate std;
+
 use core::num::{IntErrorKind, NonZeroU16, NonZeroUsize};
 use core::str::FromStr;
 
@@ -90,26 +94,121 @@ mod from_x_based {
     #[test]
     fn overflow_fails_on_zero_based() {
         assert_eq!(
-            Err(OneBasedError::OverflowIndex),
+            Err(OneBasedError::OverflowIndex {
+                value: u8::MAX as u128,
+                bits: 8
+            }),
             OneBasedU8::from_zero_based(u8::MAX)
         );
         assert_eq!(
-            Err(OneBasedError::OverflowIndex),
+            Err(OneBasedError::OverflowIndex {
+                value: u16::MAX as u128,
+                bits: 16
+            }),
             OneBasedU16::from_zero_based(u16::MAX)
         );
         assert_eq!(
-            Err(OneBasedError::OverflowIndex),
+            Err(OneBasedError::OverflowIndex {
+                value: u32::MAX as u128,
+                bits: 32
+            }),
             OneBasedU32::from_zero_based(u32::MAX)
         );
         assert_eq!(
-            Err(OneBasedError::OverflowIndex),
+            Err(OneBasedError::OverflowIndex {
+                value: u64::MAX as u128,
+                bits: 64
+            }),
             OneBasedU64::from_zero_based(u64::MAX)
         );
         assert_eq!(
-            Err(OneBasedError::OverflowIndex),
+            Err(OneBasedError::OverflowIndex {
+                value: u128::MAX,
+                bits: 128
+            }),
             OneBasedU128::from_zero_based(u128::MAX)
         );
     }
+
+    #[test]
+    fn opt_variants() {
+        assert_eq!(
+            OneBasedU8::from_one_based_opt(3).map(|v| v.as_zero_based()),
+            Some(2)
+        );
+        assert_eq!(OneBasedU8::from_one_based_opt(0), None);
+
+        assert_eq!(
+            OneBasedU16::from_zero_based_opt(3).map(|v| v.as_zero_based()),
+            Some(3)
+        );
+        assert_eq!(OneBasedU16::from_zero_based_opt(u16::MAX), None);
+    }
+
+    #[test]
+    fn last_of() {
+        assert_eq!(OneBasedUsize::last_of(0), None);
+        assert_eq!(OneBasedUsize::last_of(1).unwrap().as_zero_based(), 0);
+        assert_eq!(OneBasedUsize::last_of(5).unwrap().as_zero_based(), 4);
+    }
+
+    #[test]
+    fn from_count() {
+        let count = NonZeroUsize::new(5).unwrap();
+        assert_eq!(
+            OneBasedUsize::from_count(count),
+            OneBasedUsize::last_of(count.get()).unwrap()
+        );
+    }
+
+    #[test]
+    fn index_in() {
+        let i = OneBasedUsize::from_one_based(3).unwrap();
+        assert_eq!(i.index_in(3), Ok(2));
+        assert_eq!(
+            i.index_in(2),
+            Err(OneBasedError::OutOfBounds { index: 3, len: 2 })
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        use core::fmt::Write as _;
+
+        let mut buf: ArrayString<80> = ArrayString::new();
+        write!(&mut buf, "{}", OneBasedError::NegativeIndex { value: -3 }).unwrap();
+        assert_eq!(&buf, "negative value -3 passed as index");
+        buf.clear();
+        write!(
+            &mut buf,
+            "{}",
+            OneBasedError::OutOfRangeIndex {
+                value: 256,
+                bits: 8
+            }
+        )
+        .unwrap();
+        assert_eq!(&buf, "index 256 is too large for 8-bit type");
+        buf.clear();
+        write!(
+            &mut buf,
+            "{}",
+            OneBasedU16::from_zero_based(u16::MAX).unwrap_err()
+        )
+        .unwrap();
+        assert_eq!(
+            &buf,
+            "0-based index 65535 has no 1-based counterpart in 16-bit type"
+        );
+        buf.clear();
+        write!(
+            &mut buf,
+            "{}",
+            OneBasedError::OutOfBounds { index: 4, len: 3 }
+        )
+        .unwrap();
+        assert_eq!(&buf, "1-based index 4 is out of bounds for length 3");
+    }
 }
 
 mod from_str {
@@ -126,6 +225,254 @@ mod from_str {
         assert_eq!(&buf, "12345");
     }
 
+    #[test]
+    fn radix_format() {
+        use core::fmt::Write as _;
+
+        let v = OneBasedU32::from_one_based(255).unwrap();
+        let mut buf: ArrayString<40> = ArrayString::new();
+        write!(&mut buf, "{:#06x} {:X} {:o} {:b}", v, v, v, v).unwrap();
+        assert_eq!(&buf, "0x00ff FF 377 11111111");
+    }
+
+    #[test]
+    fn display_zero_based() {
+        use core::fmt::Write as _;
+
+        let v = OneBasedU8::from_one_based(10).unwrap();
+        let mut buf: ArrayString<10> = ArrayString::new();
+        write!(&mut buf, "{} {:>3}", v, v.display_zero_based()).unwrap();
+        assert_eq!(&buf, "10   9");
+    }
+
+    #[test]
+    fn debug_both() {
+        use core::fmt::Write as _;
+
+        let v = OneBasedU16::from_zero_based(0).unwrap();
+        let mut buf: ArrayString<40> = ArrayString::new();
+        write!(&mut buf, "{:?}", v.debug_both()).unwrap();
+        assert_eq!(&buf, "OneBasedU16 { one: 1, zero: 0 }");
+    }
+
+    #[test]
+    fn to_buffer() {
+        use core::fmt::Write as _;
+
+        assert_eq!(OneBasedU8::FIRST.to_buffer().as_str(), "1");
+        assert_eq!(OneBasedU16::MAX.to_buffer().as_str(), "65535");
+        assert_eq!(
+            &*OneBasedU128::MAX.to_buffer(),
+            "340282366920938463463374607431768211455"
+        );
+        let v = OneBasedUsize::from_one_based(1000).unwrap();
+        let mut buf: ArrayString<10> = ArrayString::new();
+        write!(&mut buf, "{:>6}", v.to_buffer()).unwrap();
+        assert_eq!(&buf, "  1000");
+
+        let mut out = [0u8; 4];
+        assert_eq!(v.write_to(&mut out), Ok("1000"));
+        let v = OneBasedU32::from_one_based(10000).unwrap();
+        assert_eq!(v.write_to(&mut out), Err(core::fmt::Error));
+    }
+
+    #[test]
+    fn ordinal() {
+        let mut buf = [0u8; 8];
+        let mut ordinal = |v: u32| {
+            OneBasedU32::from_one_based(v)
+                .unwrap()
+                .format_ordinal(&mut buf)
+                .map(ArrayString::<8>::from)
+                .unwrap()
+                .unwrap()
+        };
+        assert_eq!(&ordinal(1), "1st");
+        assert_eq!(&ordinal(2), "2nd");
+        assert_eq!(&ordinal(3), "3rd");
+        assert_eq!(&ordinal(4), "4th");
+        assert_eq!(&ordinal(11), "11th");
+        assert_eq!(&ordinal(12), "12th");
+        assert_eq!(&ordinal(13), "13th");
+        assert_eq!(&ordinal(21), "21st");
+        assert_eq!(&ordinal(101), "101st");
+        assert_eq!(&ordinal(111), "111th");
+        assert_eq!(&ordinal(1002), "1002nd");
+
+        let mut small = [0u8; 3];
+        OneBasedU8::from_one_based(100)
+            .unwrap()
+            .format_ordinal(&mut small)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn ordinal_padding() {
+        use core::fmt::Write as _;
+
+        let v = OneBasedU32::from_one_based(21).unwrap();
+        let mut buf: ArrayString<64> = ArrayString::new();
+        write!(
+            &mut buf,
+            "[{:>6}][{:<6}][{:*^7}]",
+            v.ordinal(),
+            v.ordinal(),
+            v.ordinal()
+        )
+        .unwrap();
+        assert_eq!(&buf, "[  21st][21st  ][*21st**]");
+        buf.clear();
+        write!(&mut buf, "{:2}", OneBasedU128::MAX.ordinal()).unwrap();
+        assert_eq!(&buf, "340282366920938463463374607431768211455th");
+    }
+
+    #[test]
+    fn from_ordinal_str() {
+        assert_eq!(
+            OneBasedU32::from_ordinal_str("1st")
+                .unwrap()
+                .as_zero_based(),
+            0
+        );
+        assert_eq!(
+            OneBasedU32::from_ordinal_str("13Th")
+                .unwrap()
+                .as_zero_based(),
+            12
+        );
+        assert_eq!(
+            OneBasedU32::from_ordinal_str("2st"),
+            Err(ParseOrdinalError::InvalidSuffix)
+        );
+        assert_eq!(
+            OneBasedU32::from_ordinal_str("3"),
+            Err(ParseOrdinalError::InvalidSuffix)
+        );
+        assert_eq!(
+            OneBasedU32::from_ordinal_str("11st"),
+            Err(ParseOrdinalError::InvalidSuffix)
+        );
+        match OneBasedU32::from_ordinal_str("0th") {
+            Err(ParseOrdinalError::InvalidNumber(e)) => assert_eq!(*e.kind(), IntErrorKind::Zero),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_index() {
+        let v = OneBasedU8::parse_index("255").unwrap();
+        assert_eq!(v, OneBasedU8::MAX);
+        let kind = |s: &str| OneBasedU8::parse_index(s).unwrap_err().kind();
+        assert_eq!(kind(""), ParseOneBasedErrorKind::Empty);
+        assert_eq!(kind("x"), ParseOneBasedErrorKind::InvalidNumber);
+        assert_eq!(kind("-1"), ParseOneBasedErrorKind::InvalidNumber);
+        assert_eq!(kind("256"), ParseOneBasedErrorKind::OutOfRange);
+        assert_eq!(kind("00"), ParseOneBasedErrorKind::Zero);
+
+        let err = OneBasedU8::parse_lenient("0_0").unwrap_err();
+        assert_eq!(
+            ParseOneBasedError::new("0_0", &err).kind(),
+            ParseOneBasedErrorKind::Zero
+        );
+    }
+
+    #[test]
+    fn parse_lenient() {
+        let v = OneBasedU32::parse_lenient("\t+1_234_567\n").unwrap();
+        assert_eq!(v.as_zero_based(), 1_234_566);
+        let v = OneBasedU8::parse_lenient("000_255").unwrap();
+        assert_eq!(v.as_zero_based(), 254);
+        let v = OneBasedU128::parse_lenient("340282366920938463463374607431768211455").unwrap();
+        assert_eq!(v, OneBasedU128::MAX);
+        let lenient_err = |s: &str| *OneBasedU8::parse_lenient(s).unwrap_err().kind();
+        assert_eq!(lenient_err("  "), IntErrorKind::Empty);
+        assert_eq!(lenient_err("+"), IntErrorKind::InvalidDigit);
+        assert_eq!(lenient_err("_"), IntErrorKind::InvalidDigit);
+        assert_eq!(lenient_err("-1"), IntErrorKind::InvalidDigit);
+        assert_eq!(lenient_err("1 2"), IntErrorKind::InvalidDigit);
+        assert_eq!(lenient_err("0_000"), IntErrorKind::Zero);
+        assert_eq!(lenient_err("256"), IntErrorKind::PosOverflow);
+        assert_eq!(
+            *OneBasedU128::parse_lenient("9999999999999999999999999999999999999999999")
+                .unwrap_err()
+                .kind(),
+            IntErrorKind::PosOverflow
+        );
+    }
+
+    #[test]
+    fn parse_strict() {
+        let v = OneBasedU64::parse_strict("4294967296").unwrap();
+        assert_eq!(v.as_zero_based(), 4294967295);
+        let strict_err = |s: &str| *OneBasedU16::parse_strict(s).unwrap_err().kind();
+        assert_eq!(strict_err(""), IntErrorKind::Empty);
+        assert_eq!(strict_err("0"), IntErrorKind::Zero);
+        assert_eq!(strict_err("00"), IntErrorKind::InvalidDigit);
+        assert_eq!(strict_err("01"), IntErrorKind::InvalidDigit);
+        assert_eq!(strict_err("+1"), IntErrorKind::InvalidDigit);
+        assert_eq!(strict_err(" 1"), IntErrorKind::InvalidDigit);
+        assert_eq!(strict_err("1\r\n"), IntErrorKind::InvalidDigit);
+        assert_eq!(strict_err("65536"), IntErrorKind::PosOverflow);
+    }
+
+    #[test]
+    fn parse_zero_based() {
+        let v = OneBasedU8::parse_zero_based("0").unwrap();
+        assert_eq!(v, OneBasedU8::FIRST);
+        let v = OneBasedU64::parse_zero_based("41").unwrap();
+        assert_eq!(v.as_one_based().get(), 42);
+        let v = OneBasedU8::parse_zero_based("254").unwrap();
+        assert_eq!(v, OneBasedU8::MAX);
+        assert_eq!(
+            *OneBasedU8::parse_zero_based("255").unwrap_err().kind(),
+            IntErrorKind::PosOverflow
+        );
+        assert_eq!(
+            *OneBasedU8::parse_zero_based("-0").unwrap_err().kind(),
+            IntErrorKind::InvalidDigit
+        );
+    }
+
+    #[test]
+    fn from_ascii() {
+        let v = OneBasedU32::from_ascii(b"+123").unwrap();
+        assert_eq!(v.as_zero_based(), 122);
+        let v = OneBasedU128::from_ascii(b"340282366920938463463374607431768211455").unwrap();
+        assert_eq!(v, OneBasedU128::MAX);
+        let ascii_err = |s: &[u8]| *OneBasedU8::from_ascii(s).unwrap_err().kind();
+        assert_eq!(ascii_err(b""), IntErrorKind::Empty);
+        assert_eq!(ascii_err(b"+"), IntErrorKind::InvalidDigit);
+        assert_eq!(ascii_err(b"1\xff"), IntErrorKind::InvalidDigit);
+        assert_eq!(ascii_err(b"000"), IntErrorKind::Zero);
+        assert_eq!(ascii_err(b"256"), IntErrorKind::PosOverflow);
+        for s in ["1", "+7", "0255", "256", "", "+", "-1", "1a", "0"] {
+            assert_eq!(
+                OneBasedU8::from_ascii(s.as_bytes()),
+                s.parse::<OneBasedU8>()
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_radix() {
+        let v = OneBasedU16::from_str_radix("ff", 16).unwrap();
+        assert_eq!(v.as_zero_based(), 254);
+        let v = OneBasedU8::from_str_radix("101", 2).unwrap();
+        assert_eq!(v.as_zero_based(), 4);
+        assert_eq!(
+            *OneBasedU64::from_str_radix("00", 16).unwrap_err().kind(),
+            IntErrorKind::Zero
+        );
+        assert_eq!(
+            *OneBasedU8::from_str_radix("100", 16).unwrap_err().kind(),
+            IntErrorKind::PosOverflow
+        );
+        assert_eq!(
+            *OneBasedU32::from_str_radix("g", 16).unwrap_err().kind(),
+            IntErrorKind::InvalidDigit
+        );
+    }
+
     #[test]
     fn invalid_input() {
         let err = OneBasedU8::from_str("-5").unwrap_err();
@@ -156,18 +503,215 @@ mod conversion {
         assert_eq!(v.as_zero_based(), 0);
     }
 
+    #[test]
+    fn into_usize() {
+        let v: OneBasedUsize = OneBasedU8::from_one_based(3).unwrap().into();
+        assert_eq!(v.as_zero_based(), 2);
+        let v: OneBasedUsize = OneBasedU16::from_one_based(3).unwrap().into();
+        assert_eq!(v.as_zero_based(), 2);
+        #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+        {
+            let v: OneBasedUsize = OneBasedU32::from_one_based(u32::MAX).unwrap().into();
+            assert_eq!(v.as_one_based().get(), u32::MAX as usize);
+        }
+        #[cfg(target_pointer_width = "64")]
+        {
+            let v: OneBasedUsize = OneBasedU64::from_one_based(u64::MAX).unwrap().into();
+            assert_eq!(v.as_one_based().get(), usize::MAX);
+        }
+    }
+
     #[test]
     fn try_into_ok() {
         let v = OneBasedU128::from_one_based(1).unwrap();
+        let v: OneBasedUsize = v.try_into().unwrap();
         let v: OneBasedU64 = v.try_into().unwrap();
         let v: OneBasedU32 = v.try_into().unwrap();
         let v: OneBasedU16 = v.try_into().unwrap();
         let v: OneBasedU8 = v.try_into().unwrap();
-        let v: OneBasedUsize = v.try_into().unwrap();
-        let v: OneBasedU128 = v.try_into().unwrap();
         assert_eq!(v.as_zero_based(), 0);
     }
 
+    #[test]
+    fn try_from_primitive() {
+        let v: OneBasedU32 = 5u32.try_into().unwrap();
+        assert_eq!(v.as_zero_based(), 4);
+        let v: Result<OneBasedU8, _> = 0u8.try_into();
+        assert_eq!(v, Err(OneBasedError::ZeroIndex));
+        let v: Result<OneBasedUsize, _> = 0usize.try_into();
+        assert_eq!(v, Err(OneBasedError::ZeroIndex));
+    }
+
+    #[test]
+    fn from_nonzero_and_into_primitive() {
+        let v: OneBasedU16 = NonZeroU16::new(3).unwrap().into();
+        assert_eq!(v.as_zero_based(), 2);
+        let nz: NonZeroU16 = v.into();
+        assert_eq!(nz.get(), 3);
+        let raw: u16 = v.into();
+        assert_eq!(raw, 3);
+    }
+
+    #[test]
+    fn try_from_signed() {
+        let v: OneBasedU32 = 5i32.try_into().unwrap();
+        assert_eq!(v.as_zero_based(), 4);
+        let v: OneBasedU64 = i64::MAX.try_into().unwrap();
+        assert_eq!(v.as_one_based().get(), i64::MAX as u64);
+        let v: Result<OneBasedU64, _> = 0i64.try_into();
+        assert_eq!(v, Err(OneBasedError::ZeroIndex));
+        let v: Result<OneBasedU64, _> = (-1i64).try_into();
+        assert_eq!(v, Err(OneBasedError::NegativeIndex { value: -1 }));
+        let v: Result<OneBasedUsize, _> = isize::MIN.try_into();
+        assert_eq!(
+            v,
+            Err(OneBasedError::NegativeIndex {
+                value: isize::MIN as i128
+            })
+        );
+    }
+
+    #[test]
+    fn try_from_signed_narrowing() {
+        let v: OneBasedU32 = i64::from(u32::MAX).try_into().unwrap();
+        assert_eq!(v, OneBasedU32::MAX);
+        let v: Result<OneBasedU32, _> = (i64::from(u32::MAX) + 1).try_into();
+        assert_eq!(
+            v,
+            Err(OneBasedError::OutOfRangeIndex {
+                value: 1 << 32,
+                bits: 32
+            })
+        );
+        let v: Result<OneBasedU32, _> = 0i64.try_into();
+        assert_eq!(v, Err(OneBasedError::ZeroIndex));
+        let v: Result<OneBasedU32, _> = (-5i64).try_into();
+        assert_eq!(v, Err(OneBasedError::NegativeIndex { value: -5 }));
+        let v: Result<OneBasedU8, _> = 256i32.try_into();
+        assert_eq!(
+            v,
+            Err(OneBasedError::OutOfRangeIndex {
+                value: 256,
+                bits: 8
+            })
+        );
+        let v: OneBasedU16 = 300i128.try_into().unwrap();
+        assert_eq!(v.as_zero_based(), 299);
+        let v: OneBasedU64 = 7isize.try_into().unwrap();
+        assert_eq!(v.as_one_based().get(), 7);
+    }
+
+    #[test]
+    fn into_signed() {
+        let v: i64 = OneBasedU32::from_one_based(u32::MAX).unwrap().into();
+        assert_eq!(v, i64::from(u32::MAX));
+        let v: i16 = OneBasedU8::from_zero_based(0).unwrap().into();
+        assert_eq!(v, 1);
+    }
+
+    #[test]
+    fn widen_and_try_narrow() {
+        let v = OneBasedU16::from_one_based(300).unwrap();
+        assert_eq!(v.widen::<OneBasedU64>().as_zero_based(), 299);
+        assert_eq!(
+            v.try_narrow::<OneBasedUsize>().unwrap().as_zero_based(),
+            299
+        );
+        v.try_narrow::<OneBasedU8>().unwrap_err();
+    }
+
+    #[test]
+    fn saturating_into() {
+        let v = OneBasedU32::from_one_based(300).unwrap();
+        assert_eq!(v.saturating_into_u8().as_one_based().get(), u8::MAX);
+        assert_eq!(v.saturating_into_u16().as_one_based().get(), 300);
+        assert_eq!(v.saturating_into_usize().as_zero_based(), 299);
+
+        let v = OneBasedU128::from_one_based(u128::MAX).unwrap();
+        assert_eq!(v.saturating_into_u64().as_one_based().get(), u64::MAX);
+
+        let v = OneBasedUsize::from_one_based(1).unwrap();
+        assert_eq!(v.saturating_into_u8().as_zero_based(), 0);
+    }
+
+    #[test]
+    fn to_index() {
+        let v = [1, 2, 3];
+        assert_eq!(v[OneBasedU8::from_one_based(1).unwrap().to_index()], 1);
+        assert_eq!(v[OneBasedU16::from_one_based(2).unwrap().to_index()], 2);
+        assert_eq!(v[OneBasedU32::from_one_based(3).unwrap().to_index()], 3);
+        assert_eq!(v[OneBasedUsize::from_one_based(3).unwrap().to_index()], 3);
+        let i = OneBasedU64::from_one_based(2)
+            .unwrap()
+            .try_to_index()
+            .unwrap();
+        assert_eq!(v[i], 2);
+        OneBasedU128::from_one_based(u128::MAX)
+            .unwrap()
+            .try_to_index()
+            .unwrap_err();
+    }
+
+    #[test]
+    fn bytes() {
+        let v = OneBasedU32::from_one_based(0x0102_0304).unwrap();
+        assert_eq!(v.to_le_bytes(), [4, 3, 2, 1]);
+        assert_eq!(v.to_be_bytes(), [1, 2, 3, 4]);
+        assert_eq!(OneBasedU32::from_le_bytes([4, 3, 2, 1]), Ok(v));
+        assert_eq!(OneBasedU32::from_be_bytes([1, 2, 3, 4]), Ok(v));
+        assert_eq!(OneBasedU32::from_ne_bytes(v.to_ne_bytes()), Ok(v));
+        assert_eq!(
+            OneBasedU16::from_be_bytes([0, 0]),
+            Err(OneBasedError::ZeroIndex)
+        );
+        assert_eq!(OneBasedU8::FIRST.to_le_bytes(), [1]);
+    }
+
+    #[test]
+    fn varint() {
+        let mut buf = [0u8; 32];
+        let v = OneBasedU32::from_one_based(300).unwrap();
+        assert_eq!(v.to_varint(&mut buf).unwrap(), &[0xac, 0x02]);
+        assert_eq!(OneBasedU32::from_varint(&[0xac, 0x02, 0x01]), Ok((v, 2)));
+        assert_eq!(
+            OneBasedU32::from_varint(&[0xac]),
+            Err(VarintError::Truncated)
+        );
+        assert_eq!(
+            OneBasedU8::from_varint(&[0x80, 0x02]),
+            Err(VarintError::Overflow)
+        );
+        assert_eq!(OneBasedU64::from_varint(&[0x00]), Err(VarintError::Zero));
+        assert_eq!(
+            OneBasedU32::from_varint(&[0x81, 0x00]),
+            Err(VarintError::Overlong)
+        );
+        assert_eq!(
+            OneBasedU32::from_varint(&[0x80, 0x80, 0x00]),
+            Err(VarintError::Overlong)
+        );
+        assert_eq!(
+            OneBasedU64::MAX.to_varint(&mut buf[..9]),
+            Err(VarintError::BufferTooSmall)
+        );
+
+        let encoded = OneBasedU128::MAX.to_varint(&mut buf).unwrap();
+        assert_eq!(encoded.len(), 19);
+        assert_eq!(
+            OneBasedU128::from_varint(encoded),
+            Ok((OneBasedU128::MAX, 19))
+        );
+        let encoded = OneBasedU64::MAX.to_varint(&mut buf).unwrap();
+        assert_eq!(
+            OneBasedU64::from_varint(encoded),
+            Ok((OneBasedU64::MAX, 10))
+        );
+        assert_eq!(
+            OneBasedU32::from_varint(encoded),
+            Err(VarintError::Overflow)
+        );
+    }
+
     #[test]
     fn try_into_fails() {
         let v = OneBasedU128::from_one_based(1u128.saturating_add(u64::MAX.into())).unwrap();
@@ -183,3 +727,2980 @@ mod conversion {
         let _ = <_ as TryInto<OneBasedU8>>::try_into(v).unwrap_err();
     }
 }
+
+mod compare {
+    use super::*;
+
+    use core::num::NonZeroU32;
+
+    #[test]
+    fn with_primitive() {
+        let page = OneBasedU32::from_one_based(3).unwrap();
+        assert_eq!(page, 3);
+        assert_eq!(3, page);
+        assert_ne!(page, 2);
+        assert!(page > 2);
+        assert!(page <= 3);
+        assert!(4 > page);
+        assert!(OneBasedU8::MAX == u8::MAX);
+        assert!(OneBasedUsize::FIRST > 0);
+    }
+
+    #[test]
+    fn with_nonzero() {
+        let page = OneBasedU32::from_one_based(3).unwrap();
+        let three = NonZeroU32::new(3).unwrap();
+        assert_eq!(page, three);
+        assert_eq!(three, page);
+        assert!(page < NonZeroU32::MAX);
+        assert!(NonZeroU32::MIN < page);
+    }
+}
+
+mod bits {
+    use super::*;
+
+    const FIRST_ILOG2: u32 = OneBasedU32::FIRST.ilog2();
+    const MAX_U8_ONES: u32 = OneBasedU8::MAX.count_ones();
+
+    #[test]
+    fn passthrough() {
+        let v = OneBasedU32::from_one_based(12).unwrap();
+        assert_eq!(v.get(), 12);
+        assert_eq!(v.leading_zeros(), 28);
+        assert_eq!(v.trailing_zeros(), 2);
+        assert_eq!(v.count_ones(), 2);
+        assert_eq!(v.count_zeros(), 30);
+        assert!(!v.is_power_of_two());
+        assert!(OneBasedU32::FIRST.is_power_of_two());
+        assert_eq!(v.ilog2(), 3);
+        assert_eq!(v.ilog10(), 1);
+        assert_eq!(
+            v.checked_next_power_of_two(),
+            Some(OneBasedU32::from_one_based(16).unwrap())
+        );
+        assert_eq!(OneBasedU8::MAX.checked_next_power_of_two(), None);
+        assert_eq!(MAX_U8_ONES, 8);
+        assert_eq!(FIRST_ILOG2, 0);
+    }
+}
+
+mod maybe {
+    use super::*;
+
+    #[test]
+    fn roundtrip_option() {
+        let v = OneBasedU32::from_one_based(7).unwrap();
+        let m: MaybeOneBasedU32 = Some(v).into();
+        assert_eq!(m.as_raw(), 7);
+        assert!(m.is_some());
+        assert_eq!(Option::<OneBasedU32>::from(m), Some(v));
+
+        let m: MaybeOneBasedU32 = None.into();
+        assert_eq!(m, MaybeOneBasedU32::NONE);
+        assert_eq!(m.as_raw(), 0);
+        assert!(m.is_none());
+        assert_eq!(m.get(), None);
+    }
+
+    #[test]
+    fn from_raw() {
+        assert_eq!(MaybeOneBasedU8::from_raw(0), MaybeOneBasedU8::default());
+        assert_eq!(
+            MaybeOneBasedUsize::from_raw(1).get(),
+            Some(OneBasedUsize::from_zero_based(0).unwrap())
+        );
+        assert_eq!(
+            core::mem::size_of::<MaybeOneBasedU64>(),
+            core::mem::size_of::<u64>()
+        );
+    }
+
+    #[test]
+    fn same_traits_as_one_based() {
+        fn assert_traits<T: core::hash::Hash + Eq + Copy + core::fmt::Debug + Default>() {}
+
+        assert_traits::<MaybeOneBasedU8>();
+        assert_traits::<MaybeOneBasedU128>();
+        assert_traits::<MaybeOneBasedUsize>();
+    }
+}
+
+mod stored_zero {
+    use super::*;
+
+    #[test]
+    fn same_api_as_one_based() {
+        let v = OneBasedStoredZeroU32::from_one_based(5).unwrap();
+        assert_eq!(v.as_zero_based(), 4);
+        assert_eq!(v.as_one_based().get(), 5);
+        assert_eq!(
+            OneBasedU32::from(v),
+            OneBasedU32::from_one_based(5).unwrap()
+        );
+
+        assert_eq!(
+            OneBasedStoredZeroU8::from_one_based(0),
+            Err(OneBasedError::ZeroIndex)
+        );
+        assert_eq!(
+            OneBasedStoredZeroU8::from_zero_based(u8::MAX),
+            Err(OneBasedError::OverflowIndex {
+                value: 255,
+                bits: 8
+            })
+        );
+        assert_eq!(
+            OneBasedStoredZeroU8::from_zero_based(u8::MAX - 1)
+                .unwrap()
+                .as_one_based()
+                .get(),
+            u8::MAX
+        );
+    }
+
+    #[test]
+    fn from_one_based_unchecked() {
+        let v = unsafe { OneBasedStoredZeroU64::from_one_based_unchecked(3) };
+        assert_eq!(v.as_zero_based(), 2);
+        let v = unsafe { OneBasedStoredZeroU8::from_one_based_unchecked(u8::MAX) };
+        assert_eq!(
+            v,
+            OneBasedStoredZeroU8::from_zero_based(u8::MAX - 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn same_traits_as_one_based() {
+        fn assert_traits<
+            T: core::hash::Hash + Ord + Copy + core::fmt::Debug + core::fmt::Display,
+        >() {
+        }
+
+        assert_traits::<OneBasedStoredZeroU8>();
+        assert_traits::<OneBasedStoredZeroU128>();
+        assert_traits::<OneBasedStoredZeroUsize>();
+    }
+
+    #[test]
+    fn ordering_follows_index() {
+        let a = OneBasedStoredZeroU16::from_zero_based(1).unwrap();
+        let b = OneBasedStoredZeroU16::from_zero_based(2).unwrap();
+        assert!(a < b);
+    }
+
+    #[test]
+    fn niche_is_kept() {
+        assert_eq!(
+            core::mem::size_of::<Option<OneBasedStoredZeroU64>>(),
+            core::mem::size_of::<u64>()
+        );
+    }
+
+    #[test]
+    fn format_and_parse() {
+        use core::fmt::Write as _;
+
+        let v: OneBasedStoredZeroUsize = "42".parse().unwrap();
+        assert_eq!(v.as_zero_based(), 41);
+        let mut buf: ArrayString<40> = ArrayString::new();
+        write!(&mut buf, "{} {:?}", v, v).unwrap();
+        assert_eq!(&buf, "42 OneBasedStoredZeroUsize(42)");
+    }
+}
+
+mod db_i64 {
+    use super::*;
+
+    #[test]
+    fn from_db_i64() {
+        assert_eq!(OneBasedU32::try_from_db_i64(5).unwrap().as_zero_based(), 4);
+        assert_eq!(
+            OneBasedU32::try_from_db_i64(0),
+            Err(OneBasedError::ZeroIndex)
+        );
+        assert_eq!(
+            OneBasedU64::try_from_db_i64(-3),
+            Err(OneBasedError::NegativeIndex { value: -3 })
+        );
+        assert_eq!(
+            OneBasedU8::try_from_db_i64(256),
+            Err(OneBasedError::OutOfRangeIndex {
+                value: 256,
+                bits: 8
+            })
+        );
+        assert_eq!(
+            OneBasedU128::try_from_db_i64(i64::MAX)
+                .unwrap()
+                .as_one_based()
+                .get(),
+            i64::MAX as u128
+        );
+    }
+
+    #[test]
+    fn to_db_i64() {
+        let v = OneBasedU32::from_one_based(u32::MAX).unwrap();
+        assert_eq!(v.as_db_i64(), i64::from(u32::MAX));
+        assert_eq!(v.as_sql_offset(), i64::from(u32::MAX) - 1);
+
+        let v = OneBasedU64::from_one_based(10).unwrap();
+        assert_eq!(v.try_as_db_i64(), Ok(10));
+        assert_eq!(v.try_as_sql_offset(), Ok(9));
+        OneBasedU64::from_one_based(u64::MAX)
+            .unwrap()
+            .try_as_db_i64()
+            .unwrap_err();
+    }
+}
+
+mod generic {
+    use super::*;
+
+    fn parse_max<T: UnsignedNonZero>(a: &str, b: &str) -> OneBased<T> {
+        let a: OneBased<T> = a.parse().unwrap();
+        let b: OneBased<T> = b.parse().unwrap();
+        a.max(b)
+    }
+
+    #[test]
+    fn generic_over_width() {
+        let v: OneBasedU8 = parse_max("3", "5");
+        assert_eq!(v.as_zero_based(), 4);
+        let v: OneBasedU128 = parse_max("30", "5");
+        assert_eq!(v.as_zero_based(), 29);
+    }
+
+    fn last_zero_based<I: OneBasedInteger>(v: I::Int) -> I::Int {
+        I::from_zero_based(v).unwrap().as_zero_based()
+    }
+
+    #[test]
+    fn one_based_integer_trait() {
+        assert_eq!(last_zero_based::<OneBasedU16>(7), 7);
+        assert_eq!(<OneBasedU8 as OneBasedInteger>::FIRST.as_zero_based(), 0);
+        assert_eq!(OneBasedU8::MAX.as_one_based().get(), u8::MAX);
+        assert_eq!(
+            <OneBasedU32 as OneBasedInteger>::from_one_based(0),
+            Err(OneBasedError::ZeroIndex)
+        );
+    }
+
+    #[test]
+    fn debug_keeps_alias_name() {
+        use core::fmt::Write as _;
+
+        let mut buf: ArrayString<20> = ArrayString::new();
+        write!(&mut buf, "{:?}", OneBasedU32::from_one_based(3).unwrap()).unwrap();
+        assert_eq!(&buf, "OneBasedU32(3)");
+    }
+}
+
+#[cfg(feature = "num-traits")]
+mod num_traits {
+    use super::*;
+
+    use ::num_traits::{Bounded, CheckedAdd, CheckedSub, FromPrimitive, One, ToPrimitive};
+
+    #[test]
+    fn bounded_and_one() {
+        assert_eq!(OneBasedU8::min_value(), OneBasedU8::FIRST);
+        assert_eq!(OneBasedU8::max_value().as_one_based().get(), u8::MAX);
+        assert_eq!(OneBasedU32::one().as_zero_based(), 0);
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let a = OneBasedU8::from_one_based(200).unwrap();
+        let b = OneBasedU8::from_one_based(50).unwrap();
+        assert_eq!(a.checked_add(&b).unwrap().as_one_based().get(), 250);
+        assert_eq!(a.checked_add(&a), None);
+        assert_eq!(a.checked_sub(&b).unwrap().as_one_based().get(), 150);
+        assert_eq!(a.checked_sub(&a), None);
+        assert_eq!(b.checked_sub(&a), None);
+        assert_eq!((a - b).as_one_based().get(), 150);
+    }
+
+    #[test]
+    fn primitive_conversion() {
+        let v = OneBasedU16::from_one_based(300).unwrap();
+        assert_eq!(v.to_u8(), None);
+        assert_eq!(v.to_i64(), Some(300));
+        assert_eq!(OneBasedU16::from_i64(0), None);
+        assert_eq!(OneBasedU16::from_i64(-1), None);
+        assert_eq!(OneBasedU16::from_u64(70000), None);
+        assert_eq!(OneBasedU16::from_u64(3), OneBasedU16::from_one_based_opt(3));
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck {
+    use super::*;
+
+    use ::bytemuck::{checked, TransparentWrapper};
+    use core::num::NonZeroU32;
+
+    #[test]
+    fn transparent_wrapper() {
+        let raw = [NonZeroU32::new(1).unwrap(), NonZeroU32::new(3).unwrap()];
+        let wrapped: &[OneBasedU32] = OneBasedU32::wrap_slice(&raw);
+        assert_eq!(wrapped[1].as_zero_based(), 2);
+    }
+
+    #[test]
+    fn checked_cast() {
+        let bytes: [u32; 2] = [1, 3];
+        let v: &[OneBasedU32] = checked::cast_slice(&bytes);
+        assert_eq!(v[0], OneBasedU32::FIRST);
+        let back: &[u32] = ::bytemuck::cast_slice(v);
+        assert_eq!(back, &bytes);
+
+        let bytes: [u32; 2] = [1, 0];
+        checked::try_cast_slice::<u32, OneBasedU32>(&bytes).unwrap_err();
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy {
+    use super::*;
+
+    use ::zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, TryFromBytes};
+
+    #[derive(Debug, TryFromBytes, IntoBytes, Immutable, KnownLayout)]
+    #[repr(C)]
+    struct Header {
+        len: u32,
+        index: OneBasedU32,
+    }
+
+    fn header_bytes(len: u32, index: u32) -> [u8; 8] {
+        let mut bytes = [0u8; 8];
+        bytes[..4].copy_from_slice(&len.to_ne_bytes());
+        bytes[4..].copy_from_slice(&index.to_ne_bytes());
+        bytes
+    }
+
+    #[test]
+    fn parse_from_bytes() {
+        let bytes = header_bytes(4, 2);
+        let header = Header::try_read_from_bytes(&bytes).unwrap();
+        assert_eq!(header.len, 4);
+        assert_eq!(header.index.as_zero_based(), 1);
+        assert_eq!(header.as_bytes(), &bytes[..]);
+
+        Header::try_read_from_bytes(&header_bytes(4, 0)).unwrap_err();
+    }
+
+    #[test]
+    fn raw_bytes_roundtrip() {
+        let v = OneBasedU16::from_one_based(0x1234).unwrap();
+        let raw = u16::read_from_bytes(v.as_bytes()).unwrap();
+        assert_eq!(raw, 0x1234);
+    }
+}
+
+#[cfg(feature = "english")]
+mod english {
+    use super::*;
+
+    fn parse(s: &str) -> Option<u8> {
+        OneBasedU8::from_english_words(s)
+            .ok()
+            .map(|v| v.as_one_based().get())
+    }
+
+    #[test]
+    fn cardinals() {
+        assert_eq!(parse("one"), Some(1));
+        assert_eq!(parse("Twelve"), Some(12));
+        assert_eq!(parse("twenty"), Some(20));
+        assert_eq!(parse("twenty-one"), Some(21));
+        assert_eq!(parse("ninety nine"), Some(99));
+        assert_eq!(parse("one hundred"), Some(100));
+        assert_eq!(parse("a hundred"), Some(100));
+    }
+
+    #[test]
+    fn ordinals() {
+        assert_eq!(parse("first"), Some(1));
+        assert_eq!(parse("twelfth"), Some(12));
+        assert_eq!(parse("twentieth"), Some(20));
+        assert_eq!(parse("FORTY-SECOND"), Some(42));
+        assert_eq!(parse("hundredth"), Some(100));
+        assert_eq!(parse("one hundredth"), Some(100));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("zero"), None);
+        assert_eq!(parse("a"), None);
+        assert_eq!(parse("first second"), None);
+        assert_eq!(parse("twenty twelve"), None);
+        assert_eq!(parse("twentieth one"), None);
+        assert_eq!(parse("one two"), None);
+        assert_eq!(parse("one hundred one"), None);
+        assert_eq!(
+            OneBasedU32::from_english_words("eleven-ty"),
+            Err(ParseWordsError)
+        );
+    }
+}
+
+#[cfg(feature = "num-format")]
+mod num_format {
+    use super::*;
+
+    use ::num_format::Locale;
+    use core::fmt::Write as _;
+
+    #[test]
+    fn thousands_separator() {
+        let mut buf: ArrayString<64> = ArrayString::new();
+        let v = OneBasedU64::from_one_based(1_234_567).unwrap();
+        write!(&mut buf, "{}", v.formatted(&Locale::en)).unwrap();
+        assert_eq!(&buf, "1,234,567");
+
+        buf.clear();
+        let v = OneBasedU128::MAX;
+        write!(&mut buf, "{:>10}", v.formatted(&Locale::de)).unwrap();
+        assert_eq!(&buf, "340.282.366.920.938.463.463.374.607.431.768.211.455");
+
+        buf.clear();
+        let v = OneBasedU8::FIRST;
+        write!(&mut buf, "{:>3}", v.formatted(&Locale::en)).unwrap();
+        assert_eq!(&buf, "  1");
+    }
+}
+
+#[cfg(feature = "icu")]
+mod icu {
+    use super::*;
+
+    use ::icu_locid::locale;
+    use ::icu_plurals::{PluralCategory, PluralRules};
+    use core::fmt::Write as _;
+    use one_based::OrdinalSuffixes;
+
+    #[test]
+    fn localized_ordinal() {
+        let en = PluralRules::try_new_ordinal(&locale!("en").into()).unwrap();
+        let fr = PluralRules::try_new_ordinal(&locale!("fr").into()).unwrap();
+        let de = PluralRules::try_new_ordinal(&locale!("de").into()).unwrap();
+
+        let mut buf: ArrayString<64> = ArrayString::new();
+        for v in [1u32, 2, 3, 4, 11, 12, 13, 22, 101] {
+            let v = OneBasedU32::from_one_based(v).unwrap();
+            write!(
+                &mut buf,
+                "{} ",
+                v.localized_ordinal(&en, &OrdinalSuffixes::ENGLISH)
+            )
+            .unwrap();
+        }
+        assert_eq!(&buf, "1st 2nd 3rd 4th 11th 12th 13th 22nd 101st ");
+
+        buf.clear();
+        for v in [1u8, 2, 10] {
+            let v = OneBasedU8::from_one_based(v).unwrap();
+            write!(
+                &mut buf,
+                "{} ",
+                v.localized_ordinal(&fr, &OrdinalSuffixes::FRENCH)
+            )
+            .unwrap();
+            write!(
+                &mut buf,
+                "{} ",
+                v.localized_ordinal(&de, &OrdinalSuffixes::GERMAN)
+            )
+            .unwrap();
+        }
+        assert_eq!(&buf, "1er 1. 2e 2. 10e 10. ");
+    }
+
+    #[test]
+    fn plural_category() {
+        let en = PluralRules::try_new_ordinal(&locale!("en").into()).unwrap();
+        assert_eq!(
+            OneBasedU128::FIRST.plural_category(&en),
+            PluralCategory::One
+        );
+        assert_eq!(
+            OneBasedUsize::from_one_based(23)
+                .unwrap()
+                .plural_category(&en),
+            PluralCategory::Few
+        );
+        assert_eq!(OrdinalSuffixes::ENGLISH.get(PluralCategory::Two), "nd");
+        assert_eq!(OrdinalSuffixes::uniform("x").with_one("y").other, "x");
+    }
+}
+
+#[cfg(feature = "defmt")]
+mod defmt {
+    use super::*;
+
+    fn assert_format<T: ::defmt::Format>() {}
+
+    #[test]
+    fn implements_format() {
+        assert_format::<OneBasedU8>();
+        assert_format::<OneBasedU128>();
+        assert_format::<OneBasedUsize>();
+        assert_format::<MaybeOneBasedU32>();
+        assert_format::<OneBasedStoredZeroU64>();
+    }
+}
+
+#[cfg(feature = "ufmt")]
+mod ufmt_support {
+    extern crate std;
+
+    use super::*;
+
+    use ::ufmt::uwrite;
+    use std::string::String;
+
+    #[test]
+    fn display_and_debug() {
+        let mut s = String::new();
+        let v = OneBasedU8::from_zero_based(2).unwrap();
+        uwrite!(s, "{} {:?}", v, v).unwrap();
+        assert_eq!(s, "3 3");
+
+        s.clear();
+        uwrite!(s, "{} {:?}", OneBasedU128::MAX, OneBasedUsize::FIRST).unwrap();
+        assert_eq!(s, "340282366920938463463374607431768211455 1");
+
+        s.clear();
+        let v = OneBasedStoredZeroU32::from_zero_based(0).unwrap();
+        uwrite!(s, "{} {:?}", v, v).unwrap();
+        assert_eq!(s, "1 1");
+    }
+}
+
+#[cfg(feature = "std")]
+mod with_std {
+    extern crate std;
+
+    use super::*;
+
+    use std::ffi::OsStr;
+
+    #[test]
+    fn from_os_str() {
+        let v = OneBasedU16::from_os_str(OsStr::new("12")).unwrap();
+        assert_eq!(v.as_zero_based(), 11);
+        match OneBasedU16::from_os_str(OsStr::new("0")) {
+            Err(ParseOneBasedOsError::InvalidNumber(e)) => {
+                assert_eq!(*e.kind(), IntErrorKind::Zero)
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn write_varint() {
+        let mut out = std::vec::Vec::new();
+        OneBasedU16::from_one_based(300)
+            .unwrap()
+            .write_varint(&mut out)
+            .unwrap();
+        assert_eq!(out, [0xac, 0x02]);
+    }
+
+    #[test]
+    fn parse_index_message() {
+        use std::string::ToString as _;
+
+        let err = OneBasedU16::parse_index("0").unwrap_err();
+        assert_eq!(err.input(), "0");
+        assert_eq!(err.to_string(), r#"invalid index "0": indices start at 1"#);
+        let err = OneBasedU16::parse_index("line 3").unwrap_err();
+        assert_eq!(err.to_string(), r#"invalid index "line 3": not a number"#);
+        let err = OneBasedU16::parse_index("65536").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid index "65536": number too large"#
+        );
+        let err = OneBasedU16::parse_index("").unwrap_err();
+        assert_eq!(err.to_string(), r#"invalid index "": no number given"#);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn from_os_str_not_unicode() {
+        use std::os::unix::ffi::OsStrExt as _;
+        use std::string::ToString as _;
+
+        let input = OsStr::from_bytes(b"1\xff");
+        let err = OneBasedU16::from_os_str(input).unwrap_err();
+        assert_eq!(err, ParseOneBasedOsError::NotUnicode(input.to_os_string()));
+        assert_eq!(err.to_string(), r#"index is not valid Unicode: "1\xFF""#);
+    }
+}
+
+#[cfg(feature = "roman")]
+mod roman {
+    use super::*;
+
+    use core::fmt::Write as _;
+
+    fn parse(s: &str) -> Option<u16> {
+        OneBasedU16::from_roman(s)
+            .ok()
+            .map(|v| v.as_one_based().get())
+    }
+
+    #[test]
+    fn from_roman() {
+        assert_eq!(parse("I"), Some(1));
+        assert_eq!(parse("iv"), Some(4));
+        assert_eq!(parse("XIV"), Some(14));
+        assert_eq!(parse("XLII"), Some(42));
+        assert_eq!(parse("MCMXCIV"), Some(1994));
+        assert_eq!(parse("MMMCMXCIX"), Some(3999));
+        assert_eq!(OneBasedU8::from_roman("CCL").unwrap().as_zero_based(), 249);
+        assert_eq!(OneBasedU8::from_roman("CCLVI"), Err(ParseRomanError));
+    }
+
+    #[test]
+    fn from_roman_invalid() {
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("IIII"), None);
+        assert_eq!(parse("VX"), None);
+        assert_eq!(parse("IC"), None);
+        assert_eq!(parse("CMD"), None);
+        assert_eq!(parse("MMMM"), None);
+        assert_eq!(parse("XIV "), None);
+        assert_eq!(parse("14"), None);
+        assert_eq!(parse("Ⅻ"), None);
+    }
+
+    #[test]
+    fn roman() {
+        let mut buf: ArrayString<64> = ArrayString::new();
+        for v in [1u32, 4, 9, 14, 40, 1994, 3888, 4000] {
+            let v = OneBasedU32::from_one_based(v).unwrap();
+            write!(&mut buf, "{} ", v.roman()).unwrap();
+        }
+        assert_eq!(&buf, "I IV IX XIV XL MCMXCIV MMMDCCCLXXXVIII 4000 ");
+
+        buf.clear();
+        write!(&mut buf, "{}", OneBasedU8::MAX.roman_lower()).unwrap();
+        assert_eq!(&buf, "cclv");
+    }
+
+    #[test]
+    fn roman_padding() {
+        let mut buf: ArrayString<64> = ArrayString::new();
+        let v = OneBasedU32::from_one_based(14).unwrap();
+        write!(
+            &mut buf,
+            "[{:>5}][{:<5}][{:-^7}]",
+            v.roman(),
+            v.roman_lower(),
+            v.roman()
+        )
+        .unwrap();
+        assert_eq!(&buf, "[  XIV][xiv  ][--XIV--]");
+        buf.clear();
+        let v = OneBasedU32::from_one_based(4000).unwrap();
+        write!(&mut buf, "[{:>6}]", v.roman()).unwrap();
+        assert_eq!(&buf, "[  4000]");
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut buf: ArrayString<16> = ArrayString::new();
+        for v in 1..=3999 {
+            let v = OneBasedU16::from_one_based(v).unwrap();
+            buf.clear();
+            write!(&mut buf, "{}", v.roman()).unwrap();
+            assert_eq!(OneBasedU16::from_roman(&buf), Ok(v));
+        }
+    }
+}
+
+mod locator {
+    use super::*;
+
+    use core::fmt::Write as _;
+
+    fn loc(line: usize, column: Option<usize>) -> Locator {
+        Locator {
+            line: OneBasedUsize::from_one_based(line).unwrap(),
+            column: column.map(|c| OneBasedUsize::from_one_based(c).unwrap()),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("12".parse(), Ok(loc(12, None)));
+        assert_eq!(":12".parse(), Ok(loc(12, None)));
+        assert_eq!("+12".parse(), Ok(loc(12, None)));
+        assert_eq!("12:34".parse(), Ok(loc(12, Some(34))));
+        assert_eq!(":1:1".parse(), Ok(loc(1, Some(1))));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let line_err = |s: &str| match s.parse::<Locator>() {
+            Err(ParseLocatorError::InvalidLine(e)) => *e.kind(),
+            other => panic!("unexpected result for {}: {:?}", s, other),
+        };
+        let column_err = |s: &str| match s.parse::<Locator>() {
+            Err(ParseLocatorError::InvalidColumn(e)) => *e.kind(),
+            other => panic!("unexpected result for {}: {:?}", s, other),
+        };
+        assert_eq!(line_err(""), IntErrorKind::Empty);
+        assert_eq!(line_err(":"), IntErrorKind::Empty);
+        assert_eq!(line_err("0"), IntErrorKind::Zero);
+        assert_eq!(line_err("++1"), IntErrorKind::InvalidDigit);
+        assert_eq!(line_err(":+1"), IntErrorKind::InvalidDigit);
+        assert_eq!(line_err(" 1"), IntErrorKind::InvalidDigit);
+        assert_eq!(column_err("1:"), IntErrorKind::Empty);
+        assert_eq!(column_err("1:0"), IntErrorKind::Zero);
+        assert_eq!(column_err("1:2:3"), IntErrorKind::InvalidDigit);
+    }
+
+    #[test]
+    fn display() {
+        let mut buf: ArrayString<20> = ArrayString::new();
+        write!(&mut buf, "{} {}", loc(3, None), loc(3, Some(7))).unwrap();
+        assert_eq!(&buf, "3 3:7");
+    }
+}
+
+mod range {
+    use super::*;
+
+    use core::fmt::Write as _;
+
+    fn range(start: u8, end: u8) -> OneBasedRange<OneBasedU8> {
+        OneBasedRange::new(
+            OneBasedU8::from_one_based(start).unwrap(),
+            OneBasedU8::from_one_based(end).unwrap(),
+        )
+    }
+
+    #[test]
+    fn iterate() {
+        let mut r = range(3, 5);
+        assert_eq!(r.len(), 3);
+        assert_eq!(r.next().map(|v| v.get()), Some(3));
+        assert_eq!(r.next_back().map(|v| v.get()), Some(5));
+        assert_eq!(r.len(), 1);
+        assert_eq!(r.next().map(|v| v.get()), Some(4));
+        assert!(r.is_empty());
+        assert_eq!(r.next(), None);
+        assert_eq!(r.next_back(), None);
+
+        assert!(range(5, 3).is_empty());
+        assert_eq!(range(5, 3).len(), 0);
+        assert_eq!(range(5, 3).next(), None);
+    }
+
+    #[test]
+    fn iterate_bounds() {
+        let r = OneBasedRange::new(OneBasedU8::FIRST, OneBasedU8::MAX);
+        assert_eq!(r.len(), 255);
+        assert_eq!(r.clone().fold(None, |_, v| Some(v)), Some(OneBasedU8::MAX));
+        assert_eq!(
+            r.clone().rfold(None, |_, v| Some(v)),
+            Some(OneBasedU8::FIRST)
+        );
+        assert_eq!(r.map(|v| v.get() as u32).sum::<u32>(), 255 * 256 / 2);
+
+        let mut r = OneBasedRange::new(OneBasedU128::MAX, OneBasedU128::MAX);
+        assert_eq!(r.size_hint(), (1, Some(1)));
+        assert_eq!(r.next(), Some(OneBasedU128::MAX));
+        assert_eq!(r.next(), None);
+        let r = OneBasedRange::new(OneBasedU128::FIRST, OneBasedU128::MAX);
+        assert_eq!(r.size_hint(), (usize::MAX, None));
+        assert_eq!(r.checked_len(), None);
+        let r = OneBasedRange::new(
+            OneBasedU128::FIRST,
+            OneBasedU128::from_one_based(300).unwrap(),
+        );
+        assert_eq!(r.len(), 300);
+        assert_eq!(r.checked_len(), Some(300));
+
+        // exhausted range keeps `start` next to `end` even at `MAX`.
+        let mut r = range(255, 255);
+        assert_eq!(r.next(), Some(OneBasedU8::MAX));
+        assert!(r.is_empty());
+        assert_eq!((r.start().get(), r.end().get()), (255, 254));
+        let mut r = range(1, 1);
+        assert_eq!(r.next_back(), Some(OneBasedU8::FIRST));
+        assert_eq!((r.start().get(), r.end().get()), (2, 1));
+    }
+
+    #[test]
+    fn contains() {
+        let mut r = range(3, 4);
+        assert!(!r.contains(OneBasedU8::from_one_based(2).unwrap()));
+        assert!(r.contains(OneBasedU8::from_one_based(3).unwrap()));
+        assert!(r.contains(OneBasedU8::from_one_based(4).unwrap()));
+        assert!(!r.contains(OneBasedU8::from_one_based(5).unwrap()));
+        r.by_ref().for_each(drop);
+        assert!(!r.contains(OneBasedU8::from_one_based(4).unwrap()));
+    }
+
+    #[test]
+    fn iter_from() {
+        let mut it = OneBasedU32::from_one_based(u32::MAX - 1)
+            .unwrap()
+            .iter_from();
+        assert_eq!(it.len(), 2);
+        assert_eq!(it.next().map(|v| v.get()), Some(u32::MAX - 1));
+        assert_eq!(it.next(), Some(OneBasedU32::MAX));
+        assert_eq!(it.next(), None);
+        assert_eq!(OneBasedU32::FIRST.iter_from().nth(9).unwrap().get(), 10);
+
+        let mut it = OneBasedU32::from_one_based(u32::MAX - 1)
+            .unwrap()
+            .checked_iter_from();
+        assert_eq!(it.next().unwrap().unwrap().get(), u32::MAX - 1);
+        assert_eq!(it.next(), Some(Ok(OneBasedU32::MAX)));
+        assert_eq!(
+            it.next(),
+            Some(Err(OneBasedError::OverflowIndex {
+                value: u32::MAX.into(),
+                bits: 32
+            }))
+        );
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn iter_to() {
+        let first = OneBasedU16::from_one_based(3).unwrap();
+        let last = OneBasedU16::from_one_based(5).unwrap();
+        assert!(first.iter_to(last).map(|v| v.get()).eq([3, 4, 5]));
+        assert!(last.iter_to(last).map(|v| v.get()).eq([5]));
+        assert_eq!(last.iter_to(first).next(), None);
+        assert!(OneBasedU16::MAX
+            .iter_to(OneBasedU16::MAX)
+            .eq([OneBasedU16::MAX]));
+    }
+
+    #[test]
+    fn step_by() {
+        let step = |n: usize| NonZeroUsize::new(n).unwrap();
+        let mut it = range(1, 25).step_by(step(10));
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        assert_eq!(it.next().map(|v| v.get()), Some(1));
+        assert_eq!(it.size_hint(), (2, Some(2)));
+        assert_eq!(it.next().map(|v| v.get()), Some(11));
+        assert_eq!(it.next().map(|v| v.get()), Some(21));
+        assert_eq!(it.size_hint(), (0, Some(0)));
+        assert_eq!(it.next(), None);
+
+        let last = range(1, 255).step_by(step(127)).map(|v| v.get());
+        assert!(last.eq([1, 128, 255]));
+        assert_eq!(range(3, 2).step_by(step(1)).next(), None);
+        let mut it = range(1, 255).step_by(step(usize::MAX));
+        assert_eq!(it.next().map(|v| v.get()), Some(1));
+        assert_eq!(it.next(), None);
+
+        let mut it = OneBasedRange::new(OneBasedU128::FIRST, OneBasedU128::MAX).step_by(step(2));
+        assert_eq!(it.nth(1).map(|v| v.get()), Some(3));
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!("3-7".parse(), Ok(range(3, 7)));
+        assert_eq!("5".parse(), Ok(range(5, 5)));
+        let mut buf: ArrayString<20> = ArrayString::new();
+        write!(&mut buf, "{} {}", range(3, 7), range(5, 5)).unwrap();
+        assert_eq!(&buf, "3-7 5-5");
+
+        // empty range is formatted as the reversed bounds, which isn't parsed back.
+        let mut exhausted = range(3, 5);
+        exhausted.by_ref().for_each(drop);
+        let mut buf: ArrayString<20> = ArrayString::new();
+        write!(&mut buf, "{} {}", exhausted, range(7, 3)).unwrap();
+        assert_eq!(&buf, "6-5 7-3");
+        for s in buf.split(' ') {
+            assert_eq!(
+                s.parse::<OneBasedRange<OneBasedU8>>(),
+                Err(ParseRangeError::Reversed)
+            );
+        }
+
+        let err = |s: &str| s.parse::<OneBasedRange<OneBasedU8>>().unwrap_err();
+        assert_eq!(err("7-3"), ParseRangeError::Reversed);
+        match err("0-3") {
+            ParseRangeError::InvalidStart(e) => assert_eq!(*e.kind(), IntErrorKind::Zero),
+            other => panic!("unexpected error: {:?}", other),
+        }
+        match err("3-256") {
+            ParseRangeError::InvalidEnd(e) => assert_eq!(*e.kind(), IntErrorKind::PosOverflow),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+}
+
+mod iter {
+    use super::*;
+
+    fn pos(i: usize) -> OneBasedUsize {
+        OneBasedUsize::from_one_based(i).unwrap()
+    }
+
+    #[test]
+    fn enumerate_one_based() {
+        let mut it = ["a", "b", "c", "d"].iter().copied().enumerate_one_based();
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.next(), Some((pos(1), "a")));
+        assert_eq!(it.next_back(), Some((pos(4), "d")));
+        assert_eq!(it.nth(1), Some((pos(3), "c")));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+
+        let mut it = (0..10).enumerate_one_based().skip(3);
+        assert_eq!(it.next(), Some((pos(4), 3)));
+        assert_eq!(it.next_back(), Some((pos(10), 9)));
+    }
+
+    #[test]
+    fn enumerate_one_based_last_position() {
+        let mut it = core::iter::repeat(()).enumerate_one_based();
+        assert_eq!(it.nth(usize::MAX - 1), Some((OneBasedUsize::MAX, ())));
+    }
+
+    #[test]
+    #[should_panic(expected = "too many elements for 1-based position")]
+    fn enumerate_one_based_overflow_next() {
+        let mut it = core::iter::repeat(()).enumerate_one_based();
+        it.nth(usize::MAX - 1);
+        it.next();
+    }
+
+    #[test]
+    #[should_panic(expected = "too many elements for 1-based position")]
+    fn enumerate_one_based_overflow_nth() {
+        let mut it = core::iter::repeat(()).enumerate_one_based();
+        it.next();
+        it.nth(usize::MAX);
+    }
+
+    #[test]
+    fn position_and_nth() {
+        let v = [3, 1, 4, 1, 5];
+        assert_eq!(v.iter().position_one_based(|&x| x == 1), Some(pos(2)));
+        assert_eq!(v.iter().rposition_one_based(|&x| x == 1), Some(pos(4)));
+        assert_eq!(v.iter().position_one_based(|&x| x == 9), None);
+        assert_eq!(v.iter().rposition_one_based(|&x| x == 9), None);
+
+        let mut it = v.iter();
+        assert_eq!(it.nth_one_based(pos(1)), Some(&3));
+        assert_eq!(it.nth_one_based(pos(3)), Some(&1));
+        assert_eq!(it.nth_one_based(pos(2)), None);
+    }
+}
+
+#[cfg(feature = "std")]
+mod lines {
+    extern crate std;
+
+    use super::*;
+
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[test]
+    fn numbered() {
+        let text = "a\n\nb\r\nc";
+        let lines: Vec<(usize, String)> = lines_numbered(text.as_bytes())
+            .map(|line| line.map(|(i, line)| (i.get(), line)).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                (1, "a".into()),
+                (2, "".into()),
+                (3, "b".into()),
+                (4, "c".into())
+            ]
+        );
+
+        let mut lines = lines_numbered(&b"ok\n\xFF\nok\n"[..]);
+        assert_eq!(lines.next().unwrap().unwrap().0.get(), 1);
+        assert!(lines.next().unwrap().is_err());
+        // the invalid line isn't numbered.
+        assert_eq!(lines.next().unwrap().unwrap().0.get(), 2);
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn bytes_numbered() {
+        let lines: Vec<(usize, Vec<u8>)> = byte_lines_numbered(&b"a\r\n\xFF\n\n"[..])
+            .map(|line| line.map(|(i, line)| (i.get(), line)).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            [(1, b"a".to_vec()), (2, b"\xFF".to_vec()), (3, Vec::new())]
+        );
+    }
+}
+
+mod slice {
+    use super::*;
+
+    fn range(start: usize, end: usize) -> OneBasedRange<OneBasedUsize> {
+        OneBasedRange::new(
+            OneBasedUsize::from_one_based(start).unwrap(),
+            OneBasedUsize::from_one_based(end).unwrap(),
+        )
+    }
+
+    #[test]
+    fn as_zero_based() {
+        assert_eq!(range(2, 3).as_zero_based(), 1..3);
+        assert_eq!(range(2, 2).as_zero_based(), 1..2);
+        assert_eq!(range(5, 2).as_zero_based(), 2..2);
+        let mut r = range(2, 3);
+        r.by_ref().for_each(drop);
+        assert!(r.as_zero_based().is_empty());
+    }
+
+    #[test]
+    fn get_index() {
+        let index = |i: usize| OneBasedUsize::from_one_based(i).unwrap();
+        let mut v = [10, 20, 30];
+        assert_eq!(v.get_one_based(index(1)), Some(&10));
+        assert_eq!(v.get_one_based(index(3)), Some(&30));
+        assert_eq!(v.get_one_based(index(4)), None);
+        assert_eq!(*v.one_based(index(2)), 20);
+
+        *v.get_one_based_mut(index(1)).unwrap() = 11;
+        *v.one_based_mut(index(3)) = 33;
+        assert_eq!(v.get_one_based_mut(index(4)), None);
+        assert_eq!(v, [11, 20, 33]);
+    }
+
+    #[test]
+    #[should_panic(expected = "1-based index 4 (0-based 3) is out of bounds for length 3")]
+    fn index_out_of_bounds() {
+        [10, 20, 30].one_based(OneBasedUsize::from_one_based(4).unwrap());
+    }
+
+    #[test]
+    fn get_range() {
+        let mut v = [10, 20, 30, 40];
+        assert_eq!(v.get_one_based_range(range(2, 3)), Some(&[20, 30][..]));
+        assert_eq!(v.get_one_based_range(range(1, 4)), Some(&v[..]));
+        assert_eq!(v.get_one_based_range(range(4, 5)), None);
+        // same as the inclusive range in 0-based, `3-2` is an empty range at 3.
+        assert_eq!(v.get_one_based_range(range(3, 2)), Some(&[][..]));
+        // reversed range is also empty, located at the end.
+        assert_eq!(v.get_one_based_range(range(4, 2)), Some(&[][..]));
+        assert_eq!(v.get_one_based_range(range(9, 8)), None);
+        assert_eq!(v.one_based_range(range(4, 4)), [40]);
+
+        v.get_one_based_range_mut(range(1, 2)).unwrap().fill(0);
+        v.one_based_range_mut(range(4, 4))[0] = 1;
+        assert_eq!(v, [0, 0, 30, 1]);
+    }
+
+    #[test]
+    fn reversed_range() {
+        let mut v = [0; 10];
+        // "5-2" is rejected by the parser, but can be constructed directly.
+        let reversed = range(5, 2);
+        assert!(reversed.is_empty());
+        assert_eq!(v.get_one_based_range(reversed.clone()), Some(&[][..]));
+        assert!(v.one_based_range(reversed.clone()).is_empty());
+        assert!(v.one_based_range_mut(reversed.clone()).is_empty());
+        assert!(v.one_based_view()[reversed].is_empty());
+    }
+
+    #[test]
+    fn view() {
+        let index = |i: usize| OneBasedUsize::from_one_based(i).unwrap();
+        let mut v = [10, 20, 30, 40];
+        let view = v.one_based_view();
+        assert_eq!(view[index(1)], 10);
+        assert_eq!(view[index(4)], 40);
+        assert_eq!(view[range(2, 3)], [20, 30]);
+
+        let mut view = v.one_based_view_mut();
+        view[index(1)] += 1;
+        view[range(3, 4)].fill(0);
+        assert_eq!(v, [11, 20, 0, 0]);
+
+        let mut wrapped = IndexOneBased::new(&mut v[..]);
+        wrapped[index(2)] = 22;
+        assert_eq!(wrapped.into_inner(), [11, 22, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "1-based index 5 (0-based 4) is out of bounds for length 4")]
+    fn view_out_of_bounds() {
+        let v = [10, 20, 30, 40];
+        let _ = v.one_based_view()[OneBasedUsize::from_one_based(5).unwrap()];
+    }
+
+    #[test]
+    #[should_panic(expected = "1-based range 3-5 (0-based 2..5) is out of bounds for length 4")]
+    fn range_out_of_bounds() {
+        [10, 20, 30, 40].one_based_range(range(3, 5));
+    }
+
+    #[test]
+    fn range_bounds() {
+        use core::ops::{Bound, Range, RangeBounds};
+
+        let r = range(2, 3);
+        assert_eq!(r.start_bound(), Bound::Included(&1));
+        assert_eq!(r.end_bound(), Bound::Excluded(&3));
+        let reversed = range(5, 2);
+        assert_eq!(reversed.start_bound(), Bound::Included(&2));
+        assert_eq!(reversed.end_bound(), Bound::Excluded(&2));
+
+        let v = [10, 20, 30, 40];
+        assert_eq!(v.get(Range::from(range(2, 3))), Some(&[20, 30][..]));
+        assert_eq!(v.get(Range::from(reversed)), Some(&[][..]));
+        assert_eq!(v.get(Range::from(range(4, 5))), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn drain() {
+        extern crate std;
+        use std::vec::Vec;
+
+        let mut v = std::vec![10, 20, 30, 40];
+        assert_eq!(v.drain(range(2, 3)).collect::<Vec<_>>(), [20, 30]);
+        assert_eq!(v, [10, 40]);
+        assert_eq!(v.drain(range(2, 1)).count(), 0);
+
+        let mut r = range(1, 2);
+        r.next_back();
+        r.next_back();
+        assert_eq!(v.drain(r).count(), 0);
+        assert_eq!(v, [10, 40]);
+    }
+}
+
+#[cfg(feature = "std")]
+mod field {
+    extern crate std;
+
+    use super::*;
+
+    use std::string::ToString as _;
+    use std::vec::Vec;
+
+    fn indices(fields: &FieldList, len: usize) -> Vec<usize> {
+        fields.iter(len).map(|i| i.get()).collect()
+    }
+
+    #[test]
+    fn parse_and_iterate() {
+        let fields: FieldList = "1,3-5,7-".parse().unwrap();
+        assert_eq!(indices(&fields, 10), [1, 3, 4, 5, 7, 8, 9, 10]);
+        assert_eq!(indices(&fields, 4), [1, 3, 4]);
+        assert_eq!(indices(&fields, 0), [] as [usize; 0]);
+
+        let fields: FieldList = "-2,4".parse().unwrap();
+        assert_eq!(indices(&fields, 10), [1, 2, 4]);
+    }
+
+    #[test]
+    fn merge_and_display() {
+        let fields: FieldList = "5-,3,2,1-2,4-6".parse().unwrap();
+        assert_eq!(fields.to_string(), "1-");
+        let fields: FieldList = "9,3-4,1,3".parse().unwrap();
+        assert_eq!(fields.to_string(), "1,3-4,9");
+        assert_eq!(indices(&fields, 10), [1, 3, 4, 9]);
+        assert_eq!(fields.to_string().parse(), Ok(fields));
+    }
+
+    #[test]
+    fn contains() {
+        let fields: FieldList = "2,4-5,8-".parse().unwrap();
+        let contains = |i: usize| fields.contains(OneBasedUsize::from_one_based(i).unwrap());
+        let selected: Vec<usize> = (1..=10).filter(|i| contains(*i)).collect();
+        assert_eq!(selected, [2, 4, 5, 8, 9, 10]);
+        assert!(contains(usize::MAX));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let err = |s: &str| s.parse::<FieldList>().unwrap_err();
+        assert_eq!(err(""), ParseFieldListError::EmptyField);
+        assert_eq!(err("1,,2"), ParseFieldListError::EmptyField);
+        assert_eq!(err("-"), ParseFieldListError::EmptyField);
+        assert_eq!(err("5-3"), ParseFieldListError::Reversed);
+        let kind = |s: &str| match err(s) {
+            ParseFieldListError::InvalidIndex(e) => *e.kind(),
+            other => panic!("unexpected error for {}: {:?}", s, other),
+        };
+        assert_eq!(kind("0"), IntErrorKind::Zero);
+        assert_eq!(kind("1-2-3"), IntErrorKind::InvalidDigit);
+        assert_eq!(kind("+1"), IntErrorKind::InvalidDigit);
+        assert_eq!(kind("a"), IntErrorKind::InvalidDigit);
+    }
+}
+
+#[cfg(feature = "std")]
+mod range_set {
+    extern crate std;
+
+    use super::*;
+
+    use std::string::ToString as _;
+    use std::vec::Vec;
+
+    fn range(start: u8, end: u8) -> OneBasedRange<OneBasedU8> {
+        OneBasedRange::new(
+            OneBasedU8::from_one_based(start).unwrap(),
+            OneBasedU8::from_one_based(end).unwrap(),
+        )
+    }
+
+    fn set(ranges: &[(u8, u8)]) -> OneBasedRangeSet<OneBasedU8> {
+        let mut set = OneBasedRangeSet::new();
+        for &(start, end) in ranges {
+            set.insert(range(start, end));
+        }
+        set
+    }
+
+    #[test]
+    fn insert_merges() {
+        let mut s = set(&[(5, 6), (1, 2), (10, 12)]);
+        assert_eq!(s.to_string(), "1-2,5-6,10-12");
+        s.insert(range(3, 3));
+        assert_eq!(s.to_string(), "1-3,5-6,10-12");
+        s.insert(range(4, 10));
+        assert_eq!(s.to_string(), "1-12");
+        s.insert(range(5, 4));
+        assert_eq!(s.to_string(), "1-12");
+        s.insert(range(255, 255));
+        assert_eq!(s.to_string(), "1-12,255");
+        assert!(s.contains(OneBasedU8::MAX));
+        assert!(!s.contains(OneBasedU8::from_one_based(13).unwrap()));
+        assert!(OneBasedRangeSet::<OneBasedU8>::new().is_empty());
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut s = set(&[(1, 10), (20, 30)]);
+        s.remove(range(3, 4));
+        assert_eq!(s.to_string(), "1-2,5-10,20-30");
+        s.remove(range(8, 25));
+        assert_eq!(s.to_string(), "1-2,5-7,26-30");
+        s.remove(range(1, 2));
+        assert_eq!(s.to_string(), "5-7,26-30");
+        s.remove(range(11, 12));
+        assert_eq!(s.to_string(), "5-7,26-30");
+        s.remove(range(1, 255));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (20, 20)]);
+        assert_eq!(a.union(&b).to_string(), "1-15,20");
+        assert_eq!(a.intersection(&b).to_string(), "4-5,10-11");
+        assert_eq!(
+            a.complement_within(OneBasedU8::from_one_based(12).unwrap())
+                .to_string(),
+            "6-9"
+        );
+        assert_eq!(
+            b.complement_within(OneBasedU8::MAX).to_string(),
+            "1-3,12-19,21-255"
+        );
+        assert_eq!(
+            set(&[(1, 255)]).complement_within(OneBasedU8::MAX),
+            OneBasedRangeSet::new()
+        );
+    }
+
+    #[test]
+    fn iterate() {
+        let s = set(&[(1, 2), (5, 5), (254, 255)]);
+        let ranges: Vec<OneBasedRange<OneBasedU8>> = s.ranges().collect();
+        assert_eq!(ranges, [range(1, 2), range(5, 5), range(254, 255)]);
+        let indices: Vec<u8> = s.iter().map(|i| i.get()).collect();
+        assert_eq!(indices, [1, 2, 5, 254, 255]);
+    }
+}
+
+#[cfg(feature = "std")]
+mod bit_set {
+    extern crate std;
+
+    use super::*;
+
+    use std::vec::Vec;
+
+    fn index(i: usize) -> OneBasedUsize {
+        OneBasedUsize::from_one_based(i).unwrap()
+    }
+
+    fn set(indices: &[usize]) -> OneBasedBitSet {
+        indices.iter().map(|&i| index(i)).collect()
+    }
+
+    fn indices(set: &OneBasedBitSet) -> Vec<usize> {
+        set.iter().map(|i| i.get()).collect()
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut s = OneBasedBitSet::with_max(100);
+        assert!(s.is_empty());
+        assert!(s.insert(index(1)));
+        assert!(s.insert(index(64)));
+        assert!(s.insert(index(65)));
+        assert!(!s.insert(index(65)));
+        assert_eq!(s.len(), 3);
+        assert!(s.contains(index(64)));
+        assert!(!s.contains(index(2)));
+        assert!(!s.contains(index(1000)));
+        assert_eq!(indices(&s), [1, 64, 65]);
+
+        assert!(s.remove(index(64)));
+        assert!(!s.remove(index(64)));
+        assert!(!s.remove(index(1000)));
+        assert_eq!(indices(&s), [1, 65]);
+        s.clear();
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn bulk_ops() {
+        let mut a = set(&[1, 3, 130]);
+        a.union_with(&set(&[2, 3, 200]));
+        assert_eq!(indices(&a), [1, 2, 3, 130, 200]);
+        a.intersect_with(&set(&[2, 130, 131]));
+        assert_eq!(indices(&a), [2, 130]);
+        a.difference_with(&set(&[130]));
+        assert_eq!(indices(&a), [2]);
+        assert_eq!(a, set(&[2]));
+        assert_ne!(a, set(&[2, 3]));
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    extern crate std;
+
+    use super::*;
+
+    use ::serde::{Deserialize, Serialize};
+    use std::string::String;
+
+    fn to_json<T: Serialize>(v: &T) -> String {
+        ::serde_json::to_string(v).unwrap()
+    }
+
+    fn from_json<'de, T: Deserialize<'de>>(s: &'de str) -> Result<T, String> {
+        ::serde_json::from_str(s).map_err(|e| std::format!("{}", e))
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ZeroBased {
+        #[serde(with = "one_based::serde::zero_based")]
+        index: OneBasedU8,
+        #[serde(with = "one_based::serde::zero_based::option")]
+        parent: Option<OneBasedU64>,
+    }
+
+    #[test]
+    fn zero_based() {
+        let v = ZeroBased {
+            index: OneBasedU8::FIRST,
+            parent: Some(OneBasedU64::from_one_based(10).unwrap()),
+        };
+        assert_eq!(to_json(&v), r#"{"index":0,"parent":9}"#);
+        assert_eq!(from_json(r#"{"index":0,"parent":9}"#), Ok(v));
+
+        let v = ZeroBased {
+            index: OneBasedU8::MAX,
+            parent: None,
+        };
+        assert_eq!(to_json(&v), r#"{"index":254,"parent":null}"#);
+        assert_eq!(from_json(r#"{"index":254,"parent":null}"#), Ok(v));
+
+        assert!(from_json::<ZeroBased>(r#"{"index":255,"parent":null}"#).is_err());
+        assert!(from_json::<ZeroBased>(r#"{"index":-1,"parent":null}"#).is_err());
+        assert!(from_json::<ZeroBased>(r#"{"index":0,"parent":18446744073709551615}"#).is_err());
+    }
+
+    #[test]
+    fn zero_based_error_message() {
+        assert_eq!(
+            from_json::<ZeroBased>(r#"{"index":255,"parent":null}"#),
+            Err(
+                "0-based index 255 has no 1-based counterpart in 8-bit type at line 1 column 12"
+                    .into()
+            )
+        );
+        assert_eq!(
+            from_json::<ZeroBased>(r#"{"index":0,"parent":18446744073709551615}"#),
+            Err("0-based index 18446744073709551615 has no 1-based counterpart in 64-bit type at line 1 column 41".into())
+        );
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    struct Strings {
+        #[serde(with = "one_based::serde::string")]
+        index: OneBasedU16,
+        #[serde(with = "one_based::serde::string::option", default)]
+        parent: Option<OneBasedU128>,
+    }
+
+    #[test]
+    fn string() {
+        let v = Strings {
+            index: OneBasedU16::MAX,
+            parent: Some(OneBasedU128::MAX),
+        };
+        let json = r#"{"index":"65535","parent":"340282366920938463463374607431768211455"}"#;
+        assert_eq!(to_json(&v), json);
+        assert_eq!(from_json(json), Ok(v));
+
+        let v = Strings {
+            index: OneBasedU16::FIRST,
+            parent: None,
+        };
+        assert_eq!(to_json(&v), r#"{"index":"1","parent":null}"#);
+        assert_eq!(from_json(r#"{"index":"1","parent":null}"#), Ok(v));
+        assert_eq!(from_json(r#"{"index":"1"}"#), Ok(v));
+
+        assert!(from_json::<Strings>(r#"{"index":"0"}"#).is_err());
+        assert!(from_json::<Strings>(r#"{"index":1}"#).is_err());
+        assert!(from_json::<Strings>(r#"{"index":"1","parent":"x"}"#).is_err());
+    }
+
+    #[test]
+    fn string_zero_error_message() {
+        assert_eq!(
+            from_json::<Strings>(r#"{"index":"0"}"#),
+            Err("invalid value 0: 1-based index must be >= 1 at line 1 column 12".into())
+        );
+        assert_eq!(
+            from_json::<Strings>(r#"{"index":"1","parent":"0"}"#),
+            Err("invalid value 0: 1-based index must be >= 1 at line 1 column 25".into())
+        );
+        assert_eq!(
+            from_json::<Strings>(r#"{"index":"x"}"#),
+            Err("invalid digit found in string at line 1 column 12".into())
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Flexible {
+        #[serde(with = "one_based::serde::flexible")]
+        index: OneBasedU32,
+    }
+
+    #[test]
+    fn flexible() {
+        let v = Flexible {
+            index: OneBasedU32::from_one_based(42).unwrap(),
+        };
+        assert_eq!(to_json(&v), r#"{"index":42}"#);
+        assert_eq!(from_json(r#"{"index":42}"#), Ok(v));
+        assert_eq!(
+            from_json(r#"{"index":"4294967295"}"#),
+            Ok(Flexible {
+                index: OneBasedU32::MAX
+            })
+        );
+
+        assert!(from_json::<Flexible>(r#"{"index":0}"#).is_err());
+        assert!(from_json::<Flexible>(r#"{"index":"0"}"#).is_err());
+        assert!(from_json::<Flexible>(r#"{"index":-1}"#).is_err());
+        assert!(from_json::<Flexible>(r#"{"index":"-1"}"#).is_err());
+        assert!(from_json::<Flexible>(r#"{"index":4294967296}"#).is_err());
+        assert!(from_json::<Flexible>(r#"{"index":1.5}"#).is_err());
+        assert!(from_json::<Flexible>(r#"{"index":null}"#).is_err());
+    }
+
+    #[test]
+    fn flexible_zero_error_message() {
+        assert_eq!(
+            from_json::<Flexible>(r#"{"index":0}"#),
+            Err("invalid value 0: 1-based index must be >= 1 at line 1 column 10".into())
+        );
+        assert_eq!(
+            from_json::<Flexible>(r#"{"index":"0"}"#),
+            Err("invalid value 0: 1-based index must be >= 1 at line 1 column 12".into())
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ZeroAsNone {
+        #[serde(with = "one_based::serde::zero_as_none")]
+        parent: Option<OneBasedUsize>,
+    }
+
+    #[test]
+    fn zero_as_none() {
+        let v = ZeroAsNone { parent: None };
+        assert_eq!(to_json(&v), r#"{"parent":0}"#);
+        assert_eq!(from_json(r#"{"parent":0}"#), Ok(v));
+
+        let v = ZeroAsNone {
+            parent: Some(OneBasedUsize::from_one_based(7).unwrap()),
+        };
+        assert_eq!(to_json(&v), r#"{"parent":7}"#);
+        assert_eq!(from_json(r#"{"parent":7}"#), Ok(v));
+
+        assert!(from_json::<ZeroAsNone>(r#"{"parent":null}"#).is_err());
+        assert!(from_json::<ZeroAsNone>(r#"{"parent":-1}"#).is_err());
+    }
+
+    #[test]
+    fn zero_error_message() {
+        let msg = "invalid value 0: 1-based index must be >= 1";
+        assert!(from_json::<OneBasedU32>("0").unwrap_err().starts_with(msg));
+        assert!(from_json::<OneBasedStoredZeroU8>("0")
+            .unwrap_err()
+            .starts_with(msg));
+        assert_eq!(from_json::<OneBasedU32>("1"), Ok(OneBasedU32::FIRST));
+        assert!(from_json::<OneBasedU8>("256").is_err());
+    }
+
+    #[test]
+    fn bare_integer_representation() {
+        use ::serde_test::{assert_tokens, Configure, Token};
+
+        let v = OneBasedU32::from_one_based(3).unwrap();
+        assert_tokens(&v.readable(), &[Token::U32(3)]);
+        assert_tokens(&v.compact(), &[Token::U32(3)]);
+        assert_tokens(&OneBasedU8::MAX.compact(), &[Token::U8(255)]);
+        assert_tokens(
+            &OneBasedStoredZeroU16::from_one_based(1).unwrap().readable(),
+            &[Token::U16(1)],
+        );
+        assert_tokens(&MaybeOneBasedU32::from_raw(5).readable(), &[Token::U32(5)]);
+        assert_tokens(&MaybeOneBasedU64::NONE.compact(), &[Token::U64(0)]);
+        assert_eq!(
+            to_json(&OneBasedU128::MAX),
+            "340282366920938463463374607431768211455"
+        );
+    }
+}
+
+#[cfg(feature = "serde_with")]
+mod serde_with {
+    extern crate std;
+
+    use super::*;
+
+    use ::serde::{Deserialize, Serialize};
+    use ::serde_with::serde_as;
+    use one_based::serde::{AsString, AsZeroBased, ZeroAsNone};
+    use std::{collections::BTreeMap, vec, vec::Vec};
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Container {
+        #[serde_as(as = "Vec<AsZeroBased>")]
+        rows: Vec<OneBasedU32>,
+        #[serde_as(as = "BTreeMap<AsString, AsZeroBased>")]
+        links: BTreeMap<OneBasedU8, OneBasedU16>,
+        #[serde_as(as = "Vec<ZeroAsNone>")]
+        parents: Vec<Option<OneBasedUsize>>,
+        #[serde_as(as = "Option<AsString>")]
+        next: Option<OneBasedU64>,
+    }
+
+    #[test]
+    fn serde_as() {
+        let v = Container {
+            rows: vec![OneBasedU32::FIRST, OneBasedU32::from_one_based(3).unwrap()],
+            links: BTreeMap::from([(OneBasedU8::MAX, OneBasedU16::FIRST)]),
+            parents: vec![None, Some(OneBasedUsize::from_one_based(2).unwrap())],
+            next: Some(OneBasedU64::from_one_based(5).unwrap()),
+        };
+        let json = r#"{"rows":[0,2],"links":{"255":0},"parents":[0,2],"next":"5"}"#;
+        assert_eq!(::serde_json::to_string(&v).unwrap(), json);
+        assert_eq!(::serde_json::from_str::<Container>(json).unwrap(), v);
+
+        let json = r#"{"rows":[4294967295],"links":{},"parents":[],"next":null}"#;
+        assert!(::serde_json::from_str::<Container>(json).is_err());
+    }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars {
+    use super::*;
+
+    use ::schemars::schema_for;
+    use ::serde_json::json;
+
+    #[test]
+    fn json_schema() {
+        assert_eq!(
+            schema_for!(OneBasedU8).as_value(),
+            &json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "OneBasedU8",
+                "type": "integer",
+                "format": "uint8",
+                "minimum": 1,
+                "maximum": 255,
+            })
+        );
+        assert_eq!(
+            schema_for!(OneBasedU64).as_value()["maximum"],
+            json!(u64::MAX)
+        );
+        assert_eq!(schema_for!(OneBasedU128).as_value().get("maximum"), None);
+        assert_eq!(
+            schema_for!(OneBasedStoredZeroU32).as_value()["minimum"],
+            json!(1)
+        );
+        assert_eq!(
+            schema_for!(MaybeOneBasedU16).as_value()["minimum"],
+            json!(0)
+        );
+    }
+}
+
+#[cfg(feature = "utoipa")]
+mod utoipa {
+    use super::*;
+
+    use ::serde_json::json;
+    use ::utoipa::{PartialSchema, ToSchema};
+
+    #[test]
+    fn to_schema() {
+        assert_eq!(
+            ::serde_json::to_value(OneBasedU8::schema()).unwrap(),
+            json!({"type": "integer", "format": "int32", "minimum": 1})
+        );
+        assert_eq!(
+            ::serde_json::to_value(OneBasedStoredZeroUsize::schema()).unwrap(),
+            json!({"type": "integer", "format": "int64", "minimum": 1})
+        );
+        assert_eq!(OneBasedU8::name(), "OneBasedU8");
+        assert_eq!(OneBasedStoredZeroU32::name(), "OneBasedStoredZeroU32");
+    }
+}
+
+#[cfg(feature = "bincode")]
+mod bincode {
+    use super::*;
+
+    use ::bincode::{config, decode_from_slice, encode_into_slice, error::DecodeError};
+
+    fn round_trip<T>(v: T, expected: &[u8])
+    where
+        T: ::bincode::Encode + ::bincode::Decode<()> + PartialEq + core::fmt::Debug,
+    {
+        let mut buf = [0u8; 32];
+        let len = encode_into_slice(&v, &mut buf, config::standard()).unwrap();
+        assert_eq!(&buf[..len], expected);
+        let (decoded, read): (T, usize) =
+            decode_from_slice(&buf[..len], config::standard()).unwrap();
+        assert_eq!((decoded, read), (v, len));
+    }
+
+    #[test]
+    fn encode_decode() {
+        round_trip(OneBasedU8::FIRST, &[1]);
+        round_trip(OneBasedU32::from_one_based(300).unwrap(), &[251, 44, 1]);
+        round_trip(OneBasedStoredZeroU16::from_one_based(5).unwrap(), &[5]);
+        round_trip(MaybeOneBasedU64::NONE, &[0]);
+        round_trip(MaybeOneBasedU64::from_raw(7), &[7]);
+    }
+
+    #[test]
+    fn decode_zero() {
+        assert!(matches!(
+            decode_from_slice::<OneBasedU32, _>(&[0], config::standard()),
+            Err(DecodeError::NonZeroTypeIsZero { .. })
+        ));
+        assert!(matches!(
+            decode_from_slice::<OneBasedStoredZeroU8, _>(&[0], config::standard()),
+            Err(DecodeError::NonZeroTypeIsZero { .. })
+        ));
+    }
+}
+
+#[cfg(feature = "borsh")]
+mod borsh {
+    use super::*;
+
+    use ::borsh::{from_slice, io::ErrorKind, BorshSerialize};
+    use arrayvec::ArrayVec;
+
+    fn to_bytes<T: BorshSerialize>(v: &T) -> ArrayVec<u8, 16> {
+        let mut buf = [0u8; 16];
+        let mut writer = &mut buf[..];
+        v.serialize(&mut writer).unwrap();
+        let len = 16 - writer.len();
+        buf[..len].iter().copied().collect()
+    }
+
+    #[test]
+    fn serialize() {
+        let v = OneBasedU32::from_one_based(300).unwrap();
+        assert_eq!(to_bytes(&v).as_slice(), &[44, 1, 0, 0]);
+        assert_eq!(from_slice::<OneBasedU32>(&[44, 1, 0, 0]).unwrap(), v);
+
+        let v = OneBasedStoredZeroU16::from_one_based(2).unwrap();
+        assert_eq!(to_bytes(&v).as_slice(), &[2, 0]);
+        assert_eq!(from_slice::<OneBasedStoredZeroU16>(&[2, 0]).unwrap(), v);
+
+        assert_eq!(to_bytes(&MaybeOneBasedU8::NONE).as_slice(), &[0]);
+        assert_eq!(
+            from_slice::<MaybeOneBasedU8>(&[0]).unwrap(),
+            MaybeOneBasedU8::NONE
+        );
+    }
+
+    #[test]
+    fn deserialize_zero() {
+        let err = from_slice::<OneBasedU64>(&[0; 8]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let err = from_slice::<OneBasedStoredZeroU8>(&[0]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
+
+#[cfg(feature = "musli")]
+mod musli {
+    extern crate std;
+
+    use super::*;
+
+    use ::musli::{storage, Decode, Encode};
+    use std::string::ToString;
+
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    struct Page {
+        number: OneBasedU32,
+        offset: OneBasedStoredZeroU16,
+    }
+
+    #[test]
+    fn round_trip() {
+        let v = OneBasedU32::from_one_based(300).unwrap();
+        let bytes = storage::to_vec(&v).unwrap();
+        assert_eq!(bytes, storage::to_vec(&300u32).unwrap());
+        assert_eq!(storage::from_slice::<OneBasedU32>(&bytes).unwrap(), v);
+
+        let page = Page {
+            number: OneBasedU32::MAX,
+            offset: OneBasedStoredZeroU16::from_zero_based(0).unwrap(),
+        };
+        let bytes = storage::to_vec(&page).unwrap();
+        assert_eq!(storage::from_slice::<Page>(&bytes).unwrap(), page);
+    }
+
+    #[test]
+    fn decode_zero() {
+        let bytes = storage::to_vec(&0u64).unwrap();
+        let err = storage::from_slice::<OneBasedU64>(&bytes).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("invalid value 0: 1-based index must be >= 1"));
+        let bytes = storage::to_vec(&0u8).unwrap();
+        assert!(storage::from_slice::<OneBasedStoredZeroU8>(&bytes).is_err());
+    }
+}
+
+#[cfg(feature = "rkyv")]
+mod rkyv {
+    use super::*;
+
+    extern crate std;
+
+    use ::rkyv::{access, deserialize, rancor::Error, to_bytes, Archived};
+
+    #[test]
+    fn round_trip() {
+        let v = OneBasedU64::from_one_based(12345).unwrap();
+        let bytes = to_bytes::<Error>(&v).unwrap();
+        assert_eq!(bytes.as_slice(), &12345u64.to_le_bytes());
+        let archived = access::<Archived<OneBasedU64>, Error>(&bytes).unwrap();
+        assert_eq!(archived.to_native(), v);
+        assert_eq!(deserialize::<OneBasedU64, Error>(archived).unwrap(), v);
+    }
+
+    #[test]
+    fn access_zero() {
+        let zero = to_bytes::<Error>(&0u16).unwrap();
+        assert!(access::<Archived<OneBasedU16>, Error>(&zero).is_err());
+        let one = to_bytes::<Error>(&1u16).unwrap();
+        assert!(access::<Archived<OneBasedU16>, Error>(&one).is_ok());
+    }
+}
+
+#[cfg(feature = "postcard")]
+mod postcard {
+    use super::*;
+
+    use ::postcard::{experimental::max_size::MaxSize, from_bytes, to_slice};
+
+    #[test]
+    fn max_size() {
+        assert_eq!(OneBasedU8::POSTCARD_MAX_SIZE, 1);
+        assert_eq!(OneBasedU32::POSTCARD_MAX_SIZE, 5);
+        assert_eq!(OneBasedStoredZeroU64::POSTCARD_MAX_SIZE, 10);
+        assert_eq!(MaybeOneBasedU16::POSTCARD_MAX_SIZE, 3);
+
+        let mut buf = [0u8; OneBasedU32::POSTCARD_MAX_SIZE];
+        let used = to_slice(&OneBasedU32::MAX, &mut buf).unwrap();
+        assert_eq!(used.len(), OneBasedU32::POSTCARD_MAX_SIZE);
+        assert_eq!(from_bytes::<OneBasedU32>(used).unwrap(), OneBasedU32::MAX);
+    }
+}
+
+#[cfg(feature = "minicbor")]
+mod minicbor {
+    use super::*;
+
+    extern crate std;
+
+    use ::minicbor::{decode, len, to_vec};
+    use std::string::ToString;
+
+    #[test]
+    fn encode_decode() {
+        let v = OneBasedU16::from_one_based(500).unwrap();
+        let bytes = to_vec(v).unwrap();
+        assert_eq!(bytes, [0x19, 0x01, 0xf4]);
+        assert_eq!(len(v), 3);
+        assert_eq!(decode::<OneBasedU16>(&bytes).unwrap(), v);
+    }
+
+    #[test]
+    fn decode_zero() {
+        let err = decode::<OneBasedU32>(&[0x00]).unwrap_err();
+        assert!(err.to_string().contains("1-based index must be >= 1"));
+    }
+}
+
+#[cfg(feature = "speedy")]
+mod speedy {
+    use super::*;
+
+    use ::speedy::{LittleEndian, Readable, Writable};
+
+    #[test]
+    fn read_write() {
+        let v = OneBasedU32::from_one_based(300).unwrap();
+        let mut buf = [0u8; 4];
+        v.write_to_buffer(&mut buf).unwrap();
+        assert_eq!(buf, [44, 1, 0, 0]);
+        assert_eq!(Writable::<LittleEndian>::bytes_needed(&v).unwrap(), 4);
+        assert_eq!(OneBasedU32::read_from_buffer(&buf).unwrap(), v);
+    }
+
+    #[test]
+    fn read_zero() {
+        assert!(OneBasedU16::read_from_buffer(&[0, 0]).is_err());
+    }
+}
+
+#[cfg(feature = "prost")]
+mod prost {
+    use super::*;
+
+    #[test]
+    fn proto_field() {
+        assert_eq!(
+            OneBasedU32::from_proto_field(0),
+            Err(OneBasedError::ZeroIndex)
+        );
+        let v = OneBasedU64::from_proto_field(5).unwrap();
+        assert_eq!(v.to_proto_field(), 5);
+        assert_eq!(OneBasedU64::from_proto_optional(0), None);
+        assert_eq!(OneBasedU64::from_proto_optional(5), Some(v));
+        assert_eq!(OneBasedU64::to_proto_optional(None), 0);
+        assert_eq!(OneBasedU64::to_proto_optional(Some(v)), 5);
+    }
+}
+
+#[cfg(feature = "bson")]
+mod bson {
+    use super::*;
+
+    use ::bson::Bson;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn into_bson() {
+        let v = OneBasedU16::from_one_based(7).unwrap();
+        assert_eq!(Bson::from(v), Bson::Int32(7));
+        assert_eq!(Bson::from(OneBasedU32::MAX), Bson::Int64(u32::MAX.into()));
+        let v = OneBasedU64::from_one_based(7).unwrap();
+        assert_eq!(Bson::try_from(v), Ok(Bson::Int64(7)));
+        assert!(Bson::try_from(OneBasedU64::MAX).is_err());
+    }
+
+    #[test]
+    fn from_bson() {
+        let v = OneBasedU32::from_one_based(7).unwrap();
+        assert_eq!(OneBasedU32::try_from(Bson::Int32(7)), Ok(v));
+        assert_eq!(OneBasedU32::try_from(Bson::Int64(7)), Ok(v));
+        assert_eq!(OneBasedU32::try_from(Bson::Double(7.0)), Ok(v));
+        assert_eq!(
+            OneBasedU32::try_from(Bson::Int32(0)),
+            Err(FromBsonError::InvalidIndex(OneBasedError::ZeroIndex))
+        );
+        assert_eq!(
+            OneBasedU32::try_from(Bson::Int64(-1)),
+            Err(FromBsonError::InvalidIndex(OneBasedError::NegativeIndex {
+                value: -1
+            }))
+        );
+        assert_eq!(
+            OneBasedU8::try_from(Bson::Int32(256)),
+            Err(FromBsonError::InvalidIndex(
+                OneBasedError::OutOfRangeIndex {
+                    value: 256,
+                    bits: 8
+                }
+            ))
+        );
+        assert_eq!(
+            OneBasedU32::try_from(Bson::Double(1.5)),
+            Err(FromBsonError::NotInteger)
+        );
+        assert_eq!(
+            OneBasedU32::try_from(&Bson::String("1".into())),
+            Err(FromBsonError::NotInteger)
+        );
+    }
+}
+
+#[cfg(feature = "redis")]
+mod redis {
+    use super::*;
+
+    use ::redis::{from_redis_value, ToRedisArgs, Value};
+
+    #[test]
+    fn to_redis_args() {
+        let v = OneBasedU32::from_one_based(42).unwrap();
+        assert_eq!(v.to_redis_args(), [b"42".to_vec()]);
+    }
+
+    #[test]
+    fn from_redis_value_works() {
+        let v: OneBasedU32 = from_redis_value(&Value::Int(42)).unwrap();
+        assert_eq!(v.as_zero_based(), 41);
+        let v: OneBasedU32 = from_redis_value(&Value::BulkString(b"42".to_vec())).unwrap();
+        assert_eq!(v.as_zero_based(), 41);
+        assert!(from_redis_value::<OneBasedU32>(&Value::Int(0)).is_err());
+        assert!(from_redis_value::<OneBasedU32>(&Value::Int(-1)).is_err());
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx {
+    use super::*;
+
+    extern crate std;
+
+    use ::sqlx::{Connection, SqliteConnection};
+
+    async fn round_trip() {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        let v = OneBasedU32::from_one_based(u32::MAX).unwrap();
+        let (got,): (OneBasedU32,) = ::sqlx::query_as("SELECT ?")
+            .bind(v)
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(got, v);
+
+        let (got,): (OneBasedU8,) = ::sqlx::query_as("SELECT 1")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(got, OneBasedU8::FIRST);
+
+        for invalid in ["SELECT 0", "SELECT -1", "SELECT 256"] {
+            ::sqlx::query_as::<_, (OneBasedU8,)>(invalid)
+                .fetch_one(&mut conn)
+                .await
+                .unwrap_err();
+        }
+        ::sqlx::query("SELECT ?")
+            .bind(OneBasedU64::MAX)
+            .execute(&mut conn)
+            .await
+            .unwrap_err();
+    }
+
+    #[test]
+    fn sqlite() {
+        ::tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(round_trip());
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+mod diesel {
+    use super::*;
+
+    use ::diesel::{
+        dsl::sql,
+        prelude::*,
+        sql_types::{BigInt, Integer},
+    };
+
+    #[test]
+    fn round_trip() {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        let v = OneBasedU32::from_one_based(i32::MAX as u32).unwrap();
+        let got: OneBasedU32 = ::diesel::select(v.into_sql::<Integer>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(got, v);
+        let v = OneBasedU64::from_one_based(i64::MAX as u64).unwrap();
+        let got: OneBasedU64 = ::diesel::select(v.into_sql::<BigInt>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(got, v);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        ::diesel::select(OneBasedU32::MAX.into_sql::<Integer>())
+            .get_result::<OneBasedU32>(&mut conn)
+            .unwrap_err();
+        ::diesel::select(OneBasedU64::MAX.into_sql::<BigInt>())
+            .get_result::<OneBasedU64>(&mut conn)
+            .unwrap_err();
+        ::diesel::select(sql::<Integer>("-1"))
+            .get_result::<OneBasedU32>(&mut conn)
+            .unwrap_err();
+        ::diesel::select(sql::<BigInt>("0"))
+            .get_result::<OneBasedU64>(&mut conn)
+            .unwrap_err();
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite {
+    use super::*;
+
+    use ::rusqlite::{Connection, Error};
+
+    #[test]
+    fn round_trip() {
+        let conn = Connection::open_in_memory().unwrap();
+        let v = OneBasedU64::from_one_based(i64::MAX as u64).unwrap();
+        let got: OneBasedU64 = conn.query_row("SELECT ?1", [v], |row| row.get(0)).unwrap();
+        assert_eq!(got, v);
+        let got: OneBasedU8 = conn.query_row("SELECT 1", [], |row| row.get(0)).unwrap();
+        assert_eq!(got, OneBasedU8::FIRST);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let conn = Connection::open_in_memory().unwrap();
+        for (sql, v) in [("SELECT 0", 0), ("SELECT -1", -1), ("SELECT 256", 256)] {
+            let err = conn
+                .query_row(sql, [], |row| row.get::<_, OneBasedU8>(0))
+                .unwrap_err();
+            assert_eq!(err, Error::IntegralValueOutOfRange(0, v), "{}", sql);
+        }
+        let err = conn
+            .query_row("SELECT ?1", [OneBasedU64::MAX], |row| row.get::<_, i64>(0))
+            .unwrap_err();
+        assert!(matches!(err, Error::ToSqlConversionFailure(_)));
+    }
+}
+
+#[cfg(feature = "postgres-types")]
+mod postgres_types {
+    use super::*;
+
+    use ::bytes::BytesMut;
+    use ::postgres_types::{FromSql, ToSql, Type};
+
+    fn to_bytes<T: ToSql>(v: T, ty: &Type) -> Option<BytesMut> {
+        let mut out = BytesMut::new();
+        v.to_sql_checked(ty, &mut out).ok().map(|_| out)
+    }
+
+    #[test]
+    fn to_sql() {
+        let v = OneBasedU16::from_one_based(300).unwrap();
+        assert_eq!(to_bytes(v, &Type::INT2).unwrap().as_ref(), &[1, 44]);
+        assert_eq!(to_bytes(v, &Type::INT4).unwrap().as_ref(), &[0, 0, 1, 44]);
+        assert!(to_bytes(OneBasedU16::MAX, &Type::INT2).is_none());
+        assert!(to_bytes(OneBasedU64::MAX, &Type::INT8).is_none());
+        assert!(to_bytes(v, &Type::TEXT).is_none());
+    }
+
+    #[test]
+    fn from_sql() {
+        let v = OneBasedU32::from_sql(&Type::INT4, &[0, 0, 1, 44]).unwrap();
+        assert_eq!(v.as_zero_based(), 299);
+        let v = OneBasedU8::from_sql(&Type::INT8, &[0, 0, 0, 0, 0, 0, 0, 1]).unwrap();
+        assert_eq!(v, OneBasedU8::FIRST);
+        OneBasedU32::from_sql(&Type::INT4, &[0, 0, 0, 0]).unwrap_err();
+        OneBasedU32::from_sql(&Type::INT2, &[0xff, 0xff]).unwrap_err();
+        OneBasedU8::from_sql(&Type::INT2, &[1, 0]).unwrap_err();
+        assert!(!<OneBasedU8 as FromSql>::accepts(&Type::TEXT));
+    }
+}
+
+#[cfg(feature = "sea-orm")]
+mod sea_orm {
+    use super::*;
+
+    extern crate std;
+
+    use std::collections::BTreeMap;
+
+    use ::sea_orm::{
+        sea_query::{ColumnType, ValueType},
+        ConnectionTrait, DbBackend, MockDatabase, Statement, Value,
+    };
+
+    #[test]
+    fn value() {
+        let v = OneBasedU32::from_one_based(7).unwrap();
+        assert_eq!(Value::from(v), Value::BigInt(Some(7)));
+        assert_eq!(Value::from(OneBasedU8::FIRST), Value::SmallInt(Some(1)));
+        assert_eq!(
+            <OneBasedU32 as ValueType>::try_from(Value::BigInt(Some(7))).unwrap(),
+            v
+        );
+        assert!(<OneBasedU32 as ValueType>::try_from(Value::BigInt(Some(0))).is_err());
+        assert!(<OneBasedU8 as ValueType>::try_from(Value::SmallInt(Some(256))).is_err());
+        assert_eq!(OneBasedU16::column_type(), ColumnType::Integer);
+    }
+
+    async fn query() {
+        let row = |v: i64| BTreeMap::from([("pos", Value::BigInt(Some(v)))]);
+        let conn = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[row(3)], [row(0)]])
+            .into_connection();
+        let stmt = || Statement::from_string(DbBackend::Postgres, "SELECT pos");
+
+        let res = conn.query_one(stmt()).await.unwrap().unwrap();
+        let v: OneBasedU64 = res.try_get("", "pos").unwrap();
+        assert_eq!(v.as_zero_based(), 2);
+
+        let res = conn.query_one(stmt()).await.unwrap().unwrap();
+        res.try_get::<OneBasedU64>("", "pos").unwrap_err();
+    }
+
+    #[test]
+    fn try_getable() {
+        ::tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(query());
+    }
+}
+
+#[cfg(feature = "async-graphql")]
+mod async_graphql {
+    use super::*;
+
+    use ::async_graphql::{Number, ScalarType, Value};
+
+    #[test]
+    fn scalar() {
+        let v = OneBasedU8::from_one_based(255).unwrap();
+        assert_eq!(v.to_value(), Value::Number(Number::from(255)));
+        assert_eq!(
+            <OneBasedU8 as ScalarType>::parse(Value::Number(Number::from(255))).unwrap(),
+            v
+        );
+        for invalid in [
+            Value::Number(Number::from(0)),
+            Value::Number(Number::from(-1)),
+            Value::Number(Number::from(256)),
+            Value::Number(Number::from_f64(1.5).unwrap()),
+            Value::Boolean(true),
+        ] {
+            <OneBasedU8 as ScalarType>::parse(invalid).unwrap_err();
+        }
+        assert!(!<OneBasedU8 as ScalarType>::is_valid(&Value::Number(
+            Number::from(-1)
+        )));
+    }
+}
+
+#[cfg(feature = "juniper")]
+mod juniper {
+    use super::*;
+
+    use ::juniper::{DefaultScalarValue, FromInputValue, InputValue, ToInputValue};
+
+    type Input = InputValue<DefaultScalarValue>;
+
+    fn parse<T: FromInputValue<DefaultScalarValue>>(v: Input) -> Result<T, T::Error> {
+        T::from_input_value(&v)
+    }
+
+    #[test]
+    fn scalar() {
+        let v = OneBasedU8::from_one_based(255).unwrap();
+        assert_eq!(v.to_input_value(), Input::scalar(255));
+        assert_eq!(parse::<OneBasedU8>(Input::scalar(255)), Ok(v));
+        assert_eq!(
+            parse::<OneBasedU32>(Input::scalar(i32::MAX)),
+            Ok(OneBasedU32::from_one_based(i32::MAX as u32).unwrap())
+        );
+        for invalid in [
+            Input::scalar(0),
+            Input::scalar(-1),
+            Input::scalar(256),
+            Input::scalar(1.5),
+            Input::scalar(true),
+        ] {
+            parse::<OneBasedU8>(invalid).unwrap_err();
+        }
+        assert!(parse::<OneBasedU64>(Input::scalar(0))
+            .unwrap_err()
+            .message()
+            .contains("1-based index must be >= 1"));
+    }
+
+    #[test]
+    fn output_beyond_int() {
+        assert_eq!(OneBasedU32::FIRST.to_input_value(), Input::scalar(1));
+        assert_eq!(
+            OneBasedU32::MAX.to_input_value(),
+            Input::scalar(u32::MAX as f64)
+        );
+    }
+}
+
+#[cfg(feature = "clap")]
+mod clap {
+    use super::*;
+
+    extern crate std;
+
+    use ::clap::{value_parser, Arg, Command};
+    use std::string::ToString;
+
+    fn command() -> Command {
+        Command::new("prog")
+            .arg(
+                Arg::new("line")
+                    .long("line")
+                    .value_parser(value_parser!(OneBasedU16)),
+            )
+            .arg(
+                Arg::new("page")
+                    .long("page")
+                    .value_parser(OneBasedValueParser::<OneBasedU8>::new().range(2..10)),
+            )
+    }
+
+    #[test]
+    fn value_parser() {
+        let m = command()
+            .try_get_matches_from(["prog", "--line", "3", "--page", "9"])
+            .unwrap();
+        assert_eq!(m.get_one::<OneBasedU16>("line").unwrap().as_zero_based(), 2);
+        assert_eq!(m.get_one::<OneBasedU8>("page").unwrap().as_zero_based(), 8);
+    }
+
+    #[test]
+    fn error_message() {
+        for (args, expected) in [
+            (["--line", "0"], "'--line <line>': indices start at 1"),
+            (["--line", "x"], "'--line <line>': not a number"),
+            (["--line", "65536"], "'--line <line>': number too large"),
+            (
+                ["--page", "1"],
+                "'--page <page>': index must be >= 2 and < 10",
+            ),
+            (
+                ["--page", "10"],
+                "'--page <page>': index must be >= 2 and < 10",
+            ),
+        ] {
+            let err = command()
+                .try_get_matches_from(core::iter::once("prog").chain(args))
+                .unwrap_err();
+            assert!(err.to_string().contains(expected), "{}", err);
+        }
+    }
+}
+
+#[cfg(feature = "argh")]
+mod argh {
+    use super::*;
+
+    use ::argh::FromArgValue;
+
+    #[test]
+    fn parse_arg() {
+        assert_eq!(
+            OneBasedU16::parse_arg("3").unwrap(),
+            OneBasedU16::from_one_based(3).unwrap()
+        );
+        assert_eq!(
+            OneBasedU16::parse_arg("0").unwrap_err(),
+            "invalid index \"0\": indices start at 1"
+        );
+        assert_eq!(
+            OneBasedU8::parse_arg("256").unwrap_err(),
+            "invalid index \"256\": number too large"
+        );
+    }
+
+    #[test]
+    fn from_arg_value() {
+        assert_eq!(
+            OneBasedU32::from_arg_value("7").unwrap(),
+            OneBasedU32::from_one_based(7).unwrap()
+        );
+        assert!(OneBasedU32::from_arg_value("0").is_err());
+    }
+}
+
+#[cfg(feature = "wasm")]
+mod wasm {
+    use super::*;
+
+    extern crate std;
+
+    use ::tsify::Tsify;
+    use std::string::ToString;
+
+    #[test]
+    fn typescript_decl() {
+        assert_eq!(
+            OneBasedU32::DECL,
+            r#"export type OneBasedU32 = number & { readonly __oneBased: "OneBasedU32" };"#
+        );
+        assert_eq!(
+            OneBasedU8::DECL,
+            r#"export type OneBasedU8 = number & { readonly __oneBased: "OneBasedU8" };"#
+        );
+    }
+
+    #[test]
+    fn error_message() {
+        assert_eq!(
+            FromJsValueError::NotInteger.to_string(),
+            "JavaScript value is not an integer"
+        );
+        assert_eq!(
+            FromJsValueError::from(OneBasedError::ZeroIndex).to_string(),
+            std::format!("invalid JavaScript index: {}", OneBasedError::ZeroIndex)
+        );
+    }
+}
+
+#[cfg(feature = "pyo3")]
+mod pyo3 {
+    use super::*;
+
+    use ::pyo3::{
+        exceptions::{PyOverflowError, PyTypeError, PyValueError},
+        prelude::*,
+    };
+
+    #[test]
+    fn roundtrip() {
+        Python::attach(|py| {
+            let v = OneBasedU16::from_one_based(3).unwrap();
+            let obj = v.into_pyobject(py).unwrap();
+            assert_eq!(obj.extract::<u16>().unwrap(), 3);
+            assert_eq!(obj.extract::<OneBasedU16>().unwrap(), v);
+            assert_eq!(
+                (&OneBasedU128::MAX)
+                    .into_pyobject(py)
+                    .unwrap()
+                    .extract::<u128>()
+                    .unwrap(),
+                u128::MAX
+            );
+        });
+    }
+
+    #[test]
+    fn extract_error() {
+        Python::attach(|py| {
+            let extract = |v: i64| v.into_pyobject(py).unwrap().extract::<OneBasedU8>();
+            assert!(extract(0).unwrap_err().is_instance_of::<PyValueError>(py));
+            assert!(extract(-1).unwrap_err().is_instance_of::<PyValueError>(py));
+            assert!(extract(256)
+                .unwrap_err()
+                .is_instance_of::<PyOverflowError>(py));
+            let s = "1".into_pyobject(py).unwrap();
+            assert!(s
+                .extract::<OneBasedU8>()
+                .unwrap_err()
+                .is_instance_of::<PyTypeError>(py));
+        });
+    }
+}
+
+#[cfg(feature = "mlua")]
+mod mlua {
+    use super::*;
+
+    use ::mlua::{IntoLua, Lua, Value};
+
+    #[test]
+    fn into_lua() {
+        let lua = Lua::new();
+        let v = OneBasedUsize::from_one_based(3).unwrap();
+        assert_eq!(v.into_lua(&lua).unwrap(), Value::Integer(3));
+    }
+
+    #[test]
+    fn from_lua() {
+        let lua = Lua::new();
+        let eval = |code| lua.load(code).eval::<OneBasedU8>();
+        assert_eq!(eval("return 1").unwrap(), OneBasedU8::FIRST);
+        assert_eq!(eval("return 2.0").unwrap().as_zero_based(), 1);
+        assert!(eval("return 0").is_err());
+        assert!(eval("return -1").is_err());
+        assert!(eval("return 256").is_err());
+        assert!(eval("return 1.5").is_err());
+    }
+
+    #[test]
+    fn lua_table_index() {
+        let lua = Lua::new();
+        let index: OneBasedUsize = lua
+            .load("local t = {'a', 'b', 'c'}; for i, v in ipairs(t) do if v == 'b' then return i end end")
+            .eval()
+            .unwrap();
+        assert_eq!(index.as_zero_based(), 1);
+    }
+}
+
+mod ffi {
+    use super::*;
+
+    use one_based::ffi::{OneBasedU32Ffi, OneBasedU8Ffi};
+
+    #[test]
+    fn roundtrip() {
+        assert_eq!(OneBasedU32::from_ffi(0), None);
+        let v = OneBasedU32::from_ffi(3).unwrap();
+        assert_eq!(v.as_zero_based(), 2);
+        let raw: OneBasedU32Ffi = v.to_ffi();
+        assert_eq!(raw, 3);
+        let max: OneBasedU8Ffi = OneBasedU8::MAX.to_ffi();
+        assert_eq!(max, u8::MAX);
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(
+            core::mem::size_of::<Option<OneBasedU32>>(),
+            core::mem::size_of::<OneBasedU32Ffi>()
+        );
+        assert_eq!(
+            core::mem::align_of::<OneBasedUsize>(),
+            core::mem::align_of::<usize>()
+        );
+    }
+}
+
+#[cfg(feature = "rand")]
+mod rand {
+    use super::*;
+
+    use ::rand::{distr::Uniform, rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn random_range() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let low = OneBasedU16::from_one_based(3).unwrap();
+        let high = OneBasedU16::from_one_based(5).unwrap();
+        for _ in 0..100 {
+            let v = rng.random_range(low..high);
+            assert!(low <= v && v < high, "{}", v);
+            let v = rng.random_range(OneBasedU8::FIRST..=OneBasedU8::FIRST);
+            assert_eq!(v, OneBasedU8::FIRST);
+        }
+        assert!(Uniform::new(high, low).is_err());
+    }
+
+    #[test]
+    fn standard_uniform() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..100 {
+            let v: OneBasedU8 = rng.random();
+            assert_ne!(v.as_one_based().get(), 0);
+        }
+    }
+
+    #[test]
+    fn sample_in() {
+        let mut rng = StdRng::seed_from_u64(3);
+        assert_eq!(OneBasedUsize::sample_in(&mut rng, 0), None);
+        assert_eq!(
+            OneBasedUsize::sample_in(&mut rng, 1),
+            Some(OneBasedUsize::FIRST)
+        );
+        for _ in 0..100 {
+            let v = OneBasedUsize::sample_in(&mut rng, 3).unwrap();
+            assert!(v.as_zero_based() < 3);
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    extern crate std;
+
+    use super::*;
+
+    use ::rayon::prelude::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn par_enumerate_one_based() {
+        let v: Vec<u32> = (0..1000).collect();
+        let numbered: Vec<(usize, u32)> = v
+            .par_iter()
+            .par_enumerate_one_based()
+            .map(|(i, &x)| (i.get(), x))
+            .collect();
+        assert_eq!(numbered.len(), 1000);
+        assert!(numbered.iter().all(|&(i, x)| i == x as usize + 1));
+
+        let last = v
+            .par_iter()
+            .par_enumerate_one_based()
+            .rev()
+            .map(|(i, _)| i)
+            .find_first(|_| true);
+        assert_eq!(last, OneBasedUsize::last_of(1000));
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest {
+    use super::*;
+
+    use ::proptest::{
+        prelude::*,
+        test_runner::{Config, TestError, TestRunner},
+    };
+    use one_based::proptest::{any_one_based_u8, one_based_range};
+
+    proptest! {
+        #[test]
+        fn arbitrary_is_valid(v: OneBasedU16, w in any_one_based_u8()) {
+            prop_assert_ne!(v.as_one_based().get(), 0);
+            prop_assert_ne!(w.as_one_based().get(), 0);
+        }
+
+        #[test]
+        fn range_is_within_bounds(v in one_based_range::<OneBasedU32>(3..=5)) {
+            prop_assert!((3..=5).contains(&v.as_one_based().get()));
+        }
+    }
+
+    #[test]
+    fn shrinks_to_minimal() {
+        let mut runner = TestRunner::new(Config {
+            failure_persistence: None,
+            ..Config::default()
+        });
+        let err = runner
+            .run(&any::<OneBasedU64>(), |v| {
+                prop_assert!(v.as_one_based().get() <= 100);
+                Ok(())
+            })
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TestError::Fail(_, v) if v.as_one_based().get() == 101
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "1-based range must start from 1")]
+    fn range_from_zero() {
+        let _ = one_based_range::<OneBasedU8>(0..=3);
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck {
+    use super::*;
+
+    extern crate std;
+
+    use ::quickcheck::{quickcheck, Arbitrary, Gen};
+    use std::vec::Vec;
+
+    #[test]
+    fn arbitrary_is_valid() {
+        fn prop(v: OneBasedU16, w: OneBasedU8) -> bool {
+            v.as_one_based().get() != 0 && w.as_one_based().get() != 0
+        }
+        quickcheck(prop as fn(OneBasedU16, OneBasedU8) -> bool);
+
+        let mut g = Gen::new(100);
+        let values: Vec<OneBasedU8> = (0..1000).map(|_| OneBasedU8::arbitrary(&mut g)).collect();
+        assert!(values.contains(&OneBasedU8::FIRST));
+        assert!(values.contains(&OneBasedU8::MAX));
+    }
+
+    #[test]
+    fn shrink_toward_first() {
+        let v = OneBasedU32::from_one_based(100).unwrap();
+        let shrunk: Vec<_> = v.shrink().collect();
+        assert_eq!(shrunk.first(), Some(&OneBasedU32::FIRST));
+        assert!(shrunk.iter().all(|s| *s < v));
+        assert_eq!(OneBasedU32::FIRST.shrink().count(), 0);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary {
+    use super::*;
+
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn only_valid_values() {
+        let data = [0u8; 16];
+        let mut u = Unstructured::new(&data);
+        assert_eq!(OneBasedU32::arbitrary(&mut u).unwrap(), OneBasedU32::FIRST);
+
+        let mut seen = [false; 256];
+        for b in 0..=u8::MAX {
+            let v = OneBasedU8::arbitrary(&mut Unstructured::new(&[b])).unwrap();
+            seen[v.as_one_based().get() as usize] = true;
+        }
+        assert!(!seen[0]);
+        assert!(seen[1..].iter().all(|s| *s));
+
+        // exhausted input still produces a valid value.
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(OneBasedU64::arbitrary(&mut u).unwrap(), OneBasedU64::FIRST);
+    }
+
+    #[test]
+    fn consumes_same_as_int() {
+        let data = [1, 2, 3, 4, 5, 6, 7, 8];
+        let mut u = Unstructured::new(&data);
+        let _ = OneBasedU16::arbitrary(&mut u).unwrap();
+        assert_eq!(u.len(), data.len() - 2);
+        assert_eq!(
+            <OneBasedU16 as Arbitrary>::size_hint(0),
+            <u16 as Arbitrary>::size_hint(0)
+        );
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake {
+    use super::*;
+
+    use ::fake::{
+        rand::{rngs::StdRng, SeedableRng},
+        Fake, Faker,
+    };
+
+    #[test]
+    fn faker() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..100 {
+            let v: OneBasedU8 = Faker.fake_with_rng(&mut rng);
+            assert_ne!(v.as_one_based().get(), 0);
+        }
+    }
+
+    #[test]
+    fn range() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..100 {
+            let v: OneBasedU32 = (3..5).fake_with_rng(&mut rng);
+            assert!((3..5).contains(&v.as_one_based().get()));
+            let v: OneBasedU32 = (3..=5).fake_with_rng(&mut rng);
+            assert!((3..=5).contains(&v.as_one_based().get()));
+            let v: OneBasedUsize = (..3).fake_with_rng(&mut rng);
+            assert!(v.as_zero_based() < 2);
+            let v: OneBasedU64 = (..=1).fake_with_rng(&mut rng);
+            assert_eq!(v, OneBasedU64::FIRST);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "1-based range must start from 1")]
+    fn range_from_zero() {
+        let _: OneBasedU16 = (0..3).fake();
+    }
+}
+
+mod atomic {
+    use super::*;
+
+    extern crate std;
+
+    use core::sync::atomic::Ordering;
+    use one_based::{AtomicOneBasedU32, AtomicOneBasedU64};
+    use std::{sync::Arc, thread, vec::Vec};
+
+    #[test]
+    fn load_store() {
+        let a = AtomicOneBasedU32::new(OneBasedU32::FIRST);
+        let three = OneBasedU32::from_one_based(3).unwrap();
+        a.store(three, Ordering::SeqCst);
+        assert_eq!(a.load(Ordering::SeqCst), three);
+        assert_eq!(a.swap(OneBasedU32::MAX, Ordering::SeqCst), three);
+        assert_eq!(
+            a.compare_exchange(
+                three,
+                OneBasedU32::FIRST,
+                Ordering::SeqCst,
+                Ordering::SeqCst
+            ),
+            Err(OneBasedU32::MAX)
+        );
+        assert_eq!(
+            a.compare_exchange(
+                OneBasedU32::MAX,
+                OneBasedU32::FIRST,
+                Ordering::SeqCst,
+                Ordering::SeqCst
+            ),
+            Ok(OneBasedU32::MAX)
+        );
+        assert_eq!(a.into_inner(), OneBasedU32::FIRST);
+    }
+
+    #[test]
+    fn fetch_update() {
+        let a = AtomicOneBasedU64::from(OneBasedU64::FIRST);
+        let prev = a.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |v| {
+            OneBasedU64::from_zero_based(v.as_one_based().get()).ok()
+        });
+        assert_eq!(prev, Ok(OneBasedU64::FIRST));
+        assert_eq!(a.load(Ordering::SeqCst).as_zero_based(), 1);
+        assert!(a
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |_| None)
+            .is_err());
+        assert_eq!(std::format!("{:?}", a), "AtomicOneBasedU64(2)");
+    }
+
+    #[test]
+    fn checked_fetch_add() {
+        let a = AtomicOneBasedU32::new(OneBasedU32::from_one_based(u32::MAX - 1).unwrap());
+        assert_eq!(
+            a.checked_fetch_add(1, Ordering::AcqRel).unwrap().get(),
+            u32::MAX - 1
+        );
+        assert_eq!(
+            a.checked_fetch_add(1, Ordering::Release),
+            Err(OneBasedError::OutOfRangeIndex {
+                value: u32::MAX as u128 + 1,
+                bits: 32
+            })
+        );
+        assert_eq!(a.load(Ordering::Acquire), OneBasedU32::MAX);
+    }
+
+    #[test]
+    fn concurrent() {
+        let a = Arc::new(AtomicOneBasedU32::new(OneBasedU32::FIRST));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let a = Arc::clone(&a);
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        a.checked_fetch_add(1, Ordering::Relaxed).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+        assert_eq!(a.load(Ordering::Relaxed).get(), 4001);
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+mod counter {
+    use super::*;
+
+    extern crate std;
+
+    use one_based::{CounterOverflow, OneBasedCounter};
+    use std::{collections::BTreeSet, sync::Arc, thread, vec::Vec};
+
+    #[test]
+    fn next_and_peek() {
+        let counter = OneBasedCounter::default();
+        assert_eq!(counter.peek().unwrap(), OneBasedU64::FIRST);
+        assert_eq!(counter.next().unwrap(), OneBasedU64::FIRST);
+        assert_eq!(counter.next().unwrap().get(), 2);
+        assert_eq!(counter.peek().unwrap().get(), 3);
+        assert_eq!(counter.peek().unwrap().get(), 3);
+    }
+
+    #[test]
+    fn overflow_error() {
+        let counter =
+            OneBasedCounter::starting_at(OneBasedU64::from_one_based(u64::MAX - 1).unwrap());
+        assert_eq!(counter.next().unwrap().get(), u64::MAX - 1);
+        assert_eq!(counter.next().unwrap(), OneBasedU64::MAX);
+        let exhausted = OneBasedError::OutOfRangeIndex {
+            value: u64::MAX as u128 + 1,
+            bits: 64,
+        };
+        assert_eq!(counter.peek(), Err(exhausted.clone()));
+        assert_eq!(counter.next(), Err(exhausted.clone()));
+        assert_eq!(counter.next(), Err(exhausted));
+    }
+
+    #[test]
+    fn overflow_saturate() {
+        let counter =
+            OneBasedCounter::starting_at(OneBasedU64::MAX).with_overflow(CounterOverflow::Saturate);
+        for _ in 0..3 {
+            assert_eq!(counter.next().unwrap(), OneBasedU64::MAX);
+        }
+        assert_eq!(counter.peek().unwrap(), OneBasedU64::MAX);
+    }
+
+    #[test]
+    fn concurrent_unique() {
+        let counter = Arc::new(OneBasedCounter::new());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    (0..1000)
+                        .map(|_| counter.next().unwrap().get())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        let mut seen = BTreeSet::new();
+        for h in handles {
+            for v in h.join().unwrap() {
+                assert!(seen.insert(v), "{} handed out twice", v);
+            }
+        }
+        assert_eq!(seen.len(), 4000);
+        assert_eq!(seen.first(), Some(&1));
+        assert_eq!(seen.last(), Some(&4000));
+    }
+}
+
+#[cfg(feature = "valuable")]
+mod valuable {
+    use super::*;
+
+    use ::valuable::{Valuable, Value, Visit};
+
+    #[derive(Default)]
+    struct Recorder(Option<u64>);
+
+    impl Visit for Recorder {
+        fn visit_value(&mut self, value: Value<'_>) {
+            self.0 = value.as_u64();
+        }
+    }
+
+    #[test]
+    fn as_value() {
+        let v = OneBasedU8::from_one_based(3).unwrap();
+        assert!(matches!(v.as_value(), Value::U8(3)));
+        assert!(matches!(OneBasedUsize::FIRST.as_value(), Value::Usize(1)));
+        assert!(matches!(
+            OneBasedU128::MAX.as_value(),
+            Value::U128(u128::MAX)
+        ));
+    }
+
+    #[test]
+    fn visit() {
+        let mut recorder = Recorder::default();
+        ::valuable::visit(&OneBasedU32::from_one_based(2).unwrap(), &mut recorder);
+        assert_eq!(recorder.0, Some(2));
+    }
+}
+
+#[cfg(feature = "validator")]
+mod validator {
+    use super::*;
+
+    use ::validator::Validate;
+
+    #[derive(Validate)]
+    struct Query {
+        #[validate(range(min = 1, max = 100))]
+        page: OneBasedU32,
+        #[validate(range(min = 2, exclusive_max = 10))]
+        line: OneBasedU8,
+    }
+
+    #[test]
+    fn range() {
+        let query = |page, line| Query {
+            page: OneBasedU32::from_one_based(page).unwrap(),
+            line: OneBasedU8::from_one_based(line).unwrap(),
+        };
+        assert!(query(1, 2).validate().is_ok());
+        assert!(query(100, 9).validate().is_ok());
+        let err = query(101, 2).validate().unwrap_err();
+        assert!(err.field_errors().contains_key("page"));
+        let err = query(1, 1).validate().unwrap_err();
+        assert!(err.field_errors().contains_key("line"));
+        assert!(query(1, 10).validate().is_err());
+    }
+}
+
+#[cfg(feature = "garde")]
+mod garde {
+    use super::*;
+
+    use ::garde::Validate;
+
+    #[derive(Validate)]
+    struct Query {
+        #[garde(range(min = 1, max = 100))]
+        page: OneBasedU32,
+        #[garde(range(min = 2))]
+        line: OneBasedU8,
+    }
+
+    #[test]
+    fn range() {
+        use core::fmt::Write as _;
+
+        let query = |page, line| Query {
+            page: OneBasedU32::from_one_based(page).unwrap(),
+            line: OneBasedU8::from_one_based(line).unwrap(),
+        };
+        assert!(query(1, 2).validate().is_ok());
+        assert!(query(100, u8::MAX).validate().is_ok());
+        let mut buf: ArrayString<40> = ArrayString::new();
+        write!(&mut buf, "{}", query(101, 2).validate().unwrap_err()).unwrap();
+        assert_eq!(&buf, "page: greater than 100\n");
+        buf.clear();
+        write!(&mut buf, "{}", query(1, 1).validate().unwrap_err()).unwrap();
+        assert_eq!(&buf, "line: lower than 2\n");
+    }
+}